@@ -14,16 +14,127 @@ export type FeeGovernanceHub = {
   },
   "instructions": [
     {
-      "name": "createConfig",
+      "name": "acceptAdmin",
       "discriminator": [
-        201,
-        207,
-        243,
-        114,
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addAdmin",
+      "discriminator": [
+        177,
+        236,
+        33,
+        205,
+        124,
+        152,
+        55,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "addAdminIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addFeeSchedule",
+      "discriminator": [
+        59,
+        237,
+        104,
         75,
-        111,
-        47,
-        189
+        213,
+        39,
+        92,
+        171
       ],
       "accounts": [
         {
@@ -31,6 +142,36 @@ export type FeeGovernanceHub = {
           "writable": true,
           "signer": true
         },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "config",
           "writable": true,
@@ -75,23 +216,23 @@ export type FeeGovernanceHub = {
           "name": "ix",
           "type": {
             "defined": {
-              "name": "createConfigIx"
+              "name": "addFeeScheduleIx"
             }
           }
         }
       ]
     },
     {
-      "name": "transferFees",
+      "name": "addProgramManager",
       "discriminator": [
-        103,
-        60,
-        61,
-        79,
-        56,
-        61,
-        76,
-        49
+        77,
+        45,
+        24,
+        98,
+        93,
+        24,
+        167,
+        95
       ],
       "accounts": [
         {
@@ -100,18 +241,58 @@ export type FeeGovernanceHub = {
           "signer": true
         },
         {
-          "name": "config",
+          "name": "adminRegistry",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  67,
-                  79,
+                  65,
+                  68,
+                  77,
+                  73,
                   78,
-                  70,
+                  95,
+                  82,
+                  69,
+                  71,
                   73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "programManager",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  82,
+                  79,
+                  71,
+                  82,
+                  65,
+                  77,
+                  95,
+                  77,
+                  65,
+                  78,
+                  65,
                   71,
+                  69,
+                  82,
                   95,
                   84,
                   65,
@@ -123,8 +304,8 @@ export type FeeGovernanceHub = {
                 "path": "targetProgram"
               },
               {
-                "kind": "arg",
-                "path": "ix.fee_instruction_index"
+                "kind": "account",
+                "path": "manager"
               }
             ]
           }
@@ -132,33 +313,27 @@ export type FeeGovernanceHub = {
         {
           "name": "targetProgram"
         },
+        {
+          "name": "manager"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "ix",
-          "type": {
-            "defined": {
-              "name": "transferFeesIx"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "updateConfig",
+      "name": "applyPendingUpdate",
       "discriminator": [
-        29,
+        138,
+        128,
+        124,
         158,
-        252,
-        191,
-        10,
-        83,
-        219,
-        99
+        105,
+        244,
+        245,
+        49
       ],
       "accounts": [
         {
@@ -166,14 +341,56 @@ export type FeeGovernanceHub = {
           "writable": true,
           "signer": true
         },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "config",
+          "writable": true
+        },
+        {
+          "name": "pendingUpdate",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  80,
+                  69,
+                  78,
+                  68,
+                  73,
+                  78,
+                  71,
+                  95,
                   67,
                   79,
                   78,
@@ -181,6 +398,13 @@ export type FeeGovernanceHub = {
                   73,
                   71,
                   95,
+                  85,
+                  80,
+                  68,
+                  65,
+                  84,
+                  69,
+                  95,
                   84,
                   65,
                   71
@@ -188,34 +412,3763 @@ export type FeeGovernanceHub = {
               },
               {
                 "kind": "account",
-                "path": "targetProgram"
+                "path": "pending_update.config",
+                "account": "pendingConfigUpdate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "scheduledBy",
+          "writable": true
+        },
+        {
+          "name": "programManager",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  82,
+                  79,
+                  71,
+                  82,
+                  65,
+                  77,
+                  95,
+                  77,
+                  65,
+                  78,
+                  65,
+                  71,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
               },
               {
-                "kind": "arg",
-                "path": "ix.fee_instruction_index"
+                "kind": "account",
+                "path": "config.program",
+                "account": "config"
+              },
+              {
+                "kind": "account",
+                "path": "pending_update.scheduled_by",
+                "account": "pendingConfigUpdate"
               }
             ]
           }
         },
         {
-          "name": "targetProgram"
-        }
-      ],
-      "args": [
+          "name": "feeWaiver",
+          "writable": true,
+          "optional": true
+        },
         {
-          "name": "ix",
-          "type": {
-            "defined": {
-              "name": "updateConfigIx"
-            }
-          }
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
-      ]
-    }
-  ],
-  "accounts": [
+      ],
+      "args": []
+    },
     {
-      "name": "config",
+      "name": "approveAdminProposal",
+      "discriminator": [
+        69,
+        210,
+        165,
+        107,
+        217,
+        184,
+        67,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "adminProposal"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveProposal",
+      "discriminator": [
+        136,
+        108,
+        102,
+        85,
+        98,
+        114,
+        7,
+        147
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.config",
+                "account": "configProposal"
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "configProposal"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAdminProposal",
+      "discriminator": [
+        68,
+        6,
+        145,
+        131,
+        16,
+        73,
+        182,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "adminProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelPendingUpdate",
+      "discriminator": [
+        214,
+        123,
+        119,
+        189,
+        123,
+        195,
+        71,
+        220
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingUpdate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  69,
+                  78,
+                  68,
+                  73,
+                  78,
+                  71,
+                  95,
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  85,
+                  80,
+                  68,
+                  65,
+                  84,
+                  69,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pending_update.config",
+                "account": "pendingConfigUpdate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "scheduledBy",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelProposal",
+      "discriminator": [
+        106,
+        74,
+        128,
+        146,
+        19,
+        65,
+        39,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.config",
+                "account": "configProposal"
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "configProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimFees",
+      "discriminator": [
+        82,
+        251,
+        233,
+        156,
+        12,
+        52,
+        184,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  86,
+                  65,
+                  85,
+                  76,
+                  84,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeMint",
+          "optional": true
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  86,
+                  65,
+                  85,
+                  76,
+                  84,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeVault"
+              },
+              {
+                "kind": "account",
+                "path": "fee_vault.fee_mint",
+                "account": "feeVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authorityTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeConfig",
+      "discriminator": [
+        145,
+        9,
+        72,
+        157,
+        95,
+        125,
+        61,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "rentReceiver",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "closeConfigIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createAdminProposal",
+      "discriminator": [
+        225,
+        104,
+        68,
+        10,
+        114,
+        133,
+        212,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "ix.proposal_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "createAdminProposalIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createConfig",
+      "discriminator": [
+        201,
+        207,
+        243,
+        114,
+        75,
+        111,
+        47,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defaultConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  255,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "createConfigIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createConfigsBatch",
+      "discriminator": [
+        135,
+        235,
+        231,
+        74,
+        167,
+        27,
+        116,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "defaultConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  255,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "createConfigsBatchIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createDefaultConfig",
+      "discriminator": [
+        33,
+        156,
+        94,
+        160,
+        155,
+        27,
+        160,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  255,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "createConfigIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createFeeWaiver",
+      "discriminator": [
+        141,
+        218,
+        86,
+        209,
+        203,
+        82,
+        135,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "feeWaiver",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  87,
+                  65,
+                  73,
+                  86,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "createFeeWaiverIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createGlobalConfig",
+      "discriminator": [
+        47,
+        208,
+        62,
+        51,
+        32,
+        34,
+        119,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  71,
+                  76,
+                  79,
+                  66,
+                  65,
+                  76,
+                  95,
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "createGlobalConfigIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createProposal",
+      "discriminator": [
+        132,
+        116,
+        68,
+        174,
+        216,
+        160,
+        198,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.proposal_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "createProposalIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeAdminProposal",
+      "discriminator": [
+        157,
+        50,
+        31,
+        216,
+        56,
+        72,
+        129,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "adminProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  71,
+                  76,
+                  79,
+                  66,
+                  65,
+                  76,
+                  95,
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "programManager",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "discriminator": [
+        186,
+        60,
+        116,
+        133,
+        108,
+        128,
+        111,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  80,
+                  82,
+                  79,
+                  80,
+                  79,
+                  83,
+                  65,
+                  76,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.config",
+                "account": "configProposal"
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "configProposal"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true
+        },
+        {
+          "name": "feeWaiver",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initAdminRegistry",
+      "discriminator": [
+        166,
+        91,
+        100,
+        40,
+        31,
+        42,
+        62,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initFeeStats",
+      "discriminator": [
+        114,
+        206,
+        213,
+        215,
+        29,
+        189,
+        130,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "feeStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  83,
+                  84,
+                  65,
+                  84,
+                  83,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initFeeVault",
+      "discriminator": [
+        141,
+        17,
+        88,
+        209,
+        137,
+        84,
+        89,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  86,
+                  65,
+                  85,
+                  76,
+                  84,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeMint",
+          "optional": true
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  86,
+                  65,
+                  85,
+                  76,
+                  84,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feeVault"
+              },
+              {
+                "kind": "account",
+                "path": "config.fee_mint",
+                "account": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "managerUpdateConfig",
+      "discriminator": [
+        33,
+        242,
+        7,
+        193,
+        249,
+        62,
+        214,
+        211
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programManager",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  82,
+                  79,
+                  71,
+                  82,
+                  65,
+                  77,
+                  95,
+                  77,
+                  65,
+                  78,
+                  65,
+                  71,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "updateConfigIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateConfig",
+      "discriminator": [
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
+        224,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "migrateConfigIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "proposeAdminIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "quoteFees",
+      "discriminator": [
+        129,
+        164,
+        173,
+        182,
+        58,
+        216,
+        2,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "config"
+        },
+        {
+          "name": "feeInstructionConfig",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalConfig",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  71,
+                  76,
+                  79,
+                  66,
+                  65,
+                  76,
+                  95,
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWaiver",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  87,
+                  65,
+                  73,
+                  86,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "payer"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "quoteFeesIx"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "feeQuote"
+        }
+      }
+    },
+    {
+      "name": "removeAdmin",
+      "discriminator": [
+        74,
+        202,
+        71,
+        106,
+        252,
+        31,
+        72,
+        183
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "removeAdminIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeFeeSchedule",
+      "discriminator": [
+        49,
+        52,
+        217,
+        113,
+        132,
+        151,
+        2,
+        2
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "removeFeeScheduleIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeFeeWaiver",
+      "discriminator": [
+        113,
+        127,
+        87,
+        189,
+        232,
+        254,
+        181,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWaiver",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  87,
+                  65,
+                  73,
+                  86,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "fee_waiver.config",
+                "account": "feeWaiver"
+              },
+              {
+                "kind": "account",
+                "path": "fee_waiver.payer",
+                "account": "feeWaiver"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeProgramManager",
+      "discriminator": [
+        137,
+        246,
+        160,
+        49,
+        173,
+        247,
+        164,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "programManager",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  82,
+                  79,
+                  71,
+                  82,
+                  65,
+                  77,
+                  95,
+                  77,
+                  65,
+                  78,
+                  65,
+                  71,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "program_manager.program",
+                "account": "programManager"
+              },
+              {
+                "kind": "account",
+                "path": "program_manager.manager",
+                "account": "programManager"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "scheduleConfigUpdate",
+      "discriminator": [
+        30,
+        1,
+        138,
+        84,
+        83,
+        193,
+        216,
+        72
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "programManager",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  82,
+                  79,
+                  71,
+                  82,
+                  65,
+                  77,
+                  95,
+                  77,
+                  65,
+                  78,
+                  65,
+                  71,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pendingUpdate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  69,
+                  78,
+                  68,
+                  73,
+                  78,
+                  71,
+                  95,
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  85,
+                  80,
+                  68,
+                  65,
+                  84,
+                  69,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "scheduleConfigUpdateIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setApprovalThreshold",
+      "discriminator": [
+        136,
+        178,
+        240,
+        249,
+        212,
+        42,
+        150,
+        63
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "setApprovalThresholdIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setConfigActive",
+      "discriminator": [
+        90,
+        95,
+        224,
+        173,
+        96,
+        184,
+        36,
+        136
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "setConfigActiveIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMinUpdateDelay",
+      "discriminator": [
+        235,
+        213,
+        121,
+        99,
+        170,
+        47,
+        2,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "setMinUpdateDelayIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferFees",
+      "discriminator": [
+        103,
+        60,
+        61,
+        79,
+        56,
+        61,
+        76,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "feeInstructionConfig",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalConfig",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  71,
+                  76,
+                  79,
+                  66,
+                  65,
+                  76,
+                  95,
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "feeCaller",
+          "signer": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  67,
+                  65,
+                  76,
+                  76,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "targetProgram"
+            }
+          }
+        },
+        {
+          "name": "feeMint",
+          "optional": true
+        },
+        {
+          "name": "payerTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  86,
+                  65,
+                  85,
+                  76,
+                  84,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVaultTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "feeStats",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  83,
+                  84,
+                  65,
+                  84,
+                  83,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "transferFeesIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "targetProgram"
+              },
+              {
+                "kind": "arg",
+                "path": "ix.fee_instruction_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "updateConfigIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfigsBatch",
+      "discriminator": [
+        252,
+        96,
+        232,
+        124,
+        244,
+        158,
+        243,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "targetProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "updateConfigsBatchIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeWaiver",
+      "discriminator": [
+        22,
+        246,
+        45,
+        189,
+        13,
+        119,
+        250,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeWaiver",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  70,
+                  69,
+                  69,
+                  95,
+                  87,
+                  65,
+                  73,
+                  86,
+                  69,
+                  82,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "fee_waiver.config",
+                "account": "feeWaiver"
+              },
+              {
+                "kind": "account",
+                "path": "fee_waiver.payer",
+                "account": "feeWaiver"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "updateFeeWaiverIx"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGlobalConfig",
+      "discriminator": [
+        164,
+        84,
+        130,
+        189,
+        111,
+        58,
+        250,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRegistry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  68,
+                  77,
+                  73,
+                  78,
+                  95,
+                  82,
+                  69,
+                  71,
+                  73,
+                  83,
+                  84,
+                  82,
+                  89,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "globalConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  71,
+                  76,
+                  79,
+                  66,
+                  65,
+                  76,
+                  95,
+                  67,
+                  79,
+                  78,
+                  70,
+                  73,
+                  71,
+                  95,
+                  84,
+                  65,
+                  71
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "ix",
+          "type": {
+            "defined": {
+              "name": "updateGlobalConfigIx"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminProposal",
+      "discriminator": [
+        107,
+        249,
+        66,
+        11,
+        147,
+        28,
+        12,
+        239
+      ]
+    },
+    {
+      "name": "adminRegistry",
+      "discriminator": [
+        163,
+        108,
+        201,
+        205,
+        231,
+        169,
+        240,
+        157
+      ]
+    },
+    {
+      "name": "config",
       "discriminator": [
         155,
         12,
@@ -226,33 +4179,2000 @@ export type FeeGovernanceHub = {
         204,
         130
       ]
+    },
+    {
+      "name": "configProposal",
+      "discriminator": [
+        159,
+        239,
+        163,
+        63,
+        154,
+        191,
+        78,
+        98
+      ]
+    },
+    {
+      "name": "feeStats",
+      "discriminator": [
+        178,
+        157,
+        11,
+        16,
+        173,
+        135,
+        166,
+        11
+      ]
+    },
+    {
+      "name": "feeVault",
+      "discriminator": [
+        192,
+        178,
+        69,
+        232,
+        58,
+        149,
+        157,
+        132
+      ]
+    },
+    {
+      "name": "feeWaiver",
+      "discriminator": [
+        66,
+        18,
+        17,
+        254,
+        87,
+        223,
+        159,
+        8
+      ]
+    },
+    {
+      "name": "globalConfig",
+      "discriminator": [
+        149,
+        8,
+        156,
+        202,
+        160,
+        252,
+        176,
+        217
+      ]
+    },
+    {
+      "name": "pendingConfigUpdate",
+      "discriminator": [
+        164,
+        123,
+        113,
+        201,
+        221,
+        145,
+        32,
+        155
+      ]
+    },
+    {
+      "name": "programManager",
+      "discriminator": [
+        24,
+        150,
+        74,
+        4,
+        84,
+        6,
+        141,
+        243
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "configClosed",
+      "discriminator": [
+        4,
+        138,
+        208,
+        218,
+        204,
+        236,
+        118,
+        199
+      ]
+    },
+    {
+      "name": "configCreated",
+      "discriminator": [
+        195,
+        73,
+        104,
+        161,
+        166,
+        245,
+        4,
+        120
+      ]
+    },
+    {
+      "name": "configMigrated",
+      "discriminator": [
+        115,
+        69,
+        99,
+        100,
+        192,
+        77,
+        40,
+        50
+      ]
+    },
+    {
+      "name": "configUpdateCancelled",
+      "discriminator": [
+        118,
+        51,
+        223,
+        222,
+        189,
+        52,
+        133,
+        14
+      ]
+    },
+    {
+      "name": "configUpdateScheduled",
+      "discriminator": [
+        116,
+        164,
+        109,
+        184,
+        211,
+        51,
+        131,
+        89
+      ]
+    },
+    {
+      "name": "configUpdated",
+      "discriminator": [
+        40,
+        241,
+        230,
+        122,
+        11,
+        19,
+        198,
+        194
+      ]
+    },
+    {
+      "name": "feesClaimed",
+      "discriminator": [
+        22,
+        104,
+        110,
+        222,
+        38,
+        157,
+        14,
+        62
+      ]
+    },
+    {
+      "name": "feesTransferred",
+      "discriminator": [
+        114,
+        82,
+        131,
+        33,
+        19,
+        252,
+        150,
+        174
+      ]
+    },
+    {
+      "name": "globalConfigUpdated",
+      "discriminator": [
+        232,
+        238,
+        158,
+        123,
+        210,
+        172,
+        159,
+        46
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidAuthority",
+      "msg": "Invalid Authority."
+    },
+    {
+      "code": 6001,
+      "name": "invalidInstruction",
+      "msg": "Invalid Instruction."
+    },
+    {
+      "code": 6002,
+      "name": "invalidFeeWallet",
+      "msg": "Invalid Fee Wallet."
+    },
+    {
+      "code": 6003,
+      "name": "invalidRemainingAccounts",
+      "msg": "Invalid Remaining Accounts."
+    },
+    {
+      "code": 6004,
+      "name": "globalConfigRequired",
+      "msg": "Global Config Required."
+    },
+    {
+      "code": 6005,
+      "name": "adminAlreadyExists",
+      "msg": "Admin Already Exists."
+    },
+    {
+      "code": 6006,
+      "name": "adminNotFound",
+      "msg": "Admin Not Found."
+    },
+    {
+      "code": 6007,
+      "name": "exceedMaxNumAdmins",
+      "msg": "Exceed Max Num Admins."
+    },
+    {
+      "code": 6008,
+      "name": "cannotRemoveLastAdmin",
+      "msg": "Cannot Remove Last Admin."
+    },
+    {
+      "code": 6009,
+      "name": "invalidPendingAdmin",
+      "msg": "Invalid Pending Admin."
+    },
+    {
+      "code": 6010,
+      "name": "invalidApprovalThreshold",
+      "msg": "Invalid Approval Threshold."
+    },
+    {
+      "code": 6011,
+      "name": "proposalRequired",
+      "msg": "Proposal Required."
+    },
+    {
+      "code": 6012,
+      "name": "alreadyApproved",
+      "msg": "Already Approved."
+    },
+    {
+      "code": 6013,
+      "name": "notEnoughApprovals",
+      "msg": "Not Enough Approvals."
+    },
+    {
+      "code": 6014,
+      "name": "timelockRequired",
+      "msg": "Timelock Required."
+    },
+    {
+      "code": 6015,
+      "name": "timelockNotElapsed",
+      "msg": "Timelock Not Elapsed."
+    },
+    {
+      "code": 6016,
+      "name": "invalidEffectiveAt",
+      "msg": "Invalid Effective At."
+    },
+    {
+      "code": 6017,
+      "name": "invalidFeeMode",
+      "msg": "Invalid Fee Mode."
+    },
+    {
+      "code": 6018,
+      "name": "mathOverflow",
+      "msg": "MathOverflow."
+    },
+    {
+      "code": 6019,
+      "name": "tokenAccountsRequired",
+      "msg": "Token Accounts Required."
+    },
+    {
+      "code": 6020,
+      "name": "invalidFeeMint",
+      "msg": "Invalid Fee Mint."
+    },
+    {
+      "code": 6021,
+      "name": "feePercentTooHigh",
+      "msg": "Fee Percent Too High."
+    },
+    {
+      "code": 6022,
+      "name": "zeroPercentFeeWallet",
+      "msg": "Zero Percent Fee Wallet."
+    },
+    {
+      "code": 6023,
+      "name": "duplicateFeeWallet",
+      "msg": "Duplicate Fee Wallet."
+    },
+    {
+      "code": 6024,
+      "name": "invalidFeePercentSum",
+      "msg": "Invalid Fee Percent Sum."
+    },
+    {
+      "code": 6025,
+      "name": "invalidRemainderTreasury",
+      "msg": "Invalid Remainder Treasury."
+    },
+    {
+      "code": 6026,
+      "name": "exceedMaxNumFeeWallets",
+      "msg": "Exceed Max Num Fee Wallets."
+    },
+    {
+      "code": 6027,
+      "name": "configInactive",
+      "msg": "Config Inactive."
+    },
+    {
+      "code": 6028,
+      "name": "invalidDiscountPercent",
+      "msg": "Invalid Discount Percent."
+    },
+    {
+      "code": 6029,
+      "name": "feeVaultRequired",
+      "msg": "Fee Vault Required."
+    },
+    {
+      "code": 6030,
+      "name": "exceedMaxNumFeeBalances",
+      "msg": "Exceed Max Num Fee Balances."
+    },
+    {
+      "code": 6031,
+      "name": "nothingToClaim",
+      "msg": "Nothing To Claim."
+    },
+    {
+      "code": 6032,
+      "name": "invalidConfig",
+      "msg": "Invalid Config."
+    },
+    {
+      "code": 6033,
+      "name": "instructionConfigRequired",
+      "msg": "Instruction Config Required."
+    },
+    {
+      "code": 6034,
+      "name": "instructionConfigExists",
+      "msg": "Instruction Config Exists."
+    },
+    {
+      "code": 6035,
+      "name": "invalidFeeSchedule",
+      "msg": "Invalid Fee Schedule."
+    },
+    {
+      "code": 6036,
+      "name": "feeScheduleOverlap",
+      "msg": "Fee Schedule Overlap."
+    },
+    {
+      "code": 6037,
+      "name": "exceedMaxNumFeeSchedules",
+      "msg": "Exceed Max Num Fee Schedules."
+    },
+    {
+      "code": 6038,
+      "name": "feeScheduleNotFound",
+      "msg": "Fee Schedule Not Found."
+    },
+    {
+      "code": 6039,
+      "name": "globalFeeWalletsAdminOnly",
+      "msg": "Global Fee Wallets Admin Only."
+    },
+    {
+      "code": 6040,
+      "name": "invalidPlatformSharePercent",
+      "msg": "Invalid Platform Share Percent."
+    },
+    {
+      "code": 6041,
+      "name": "configMigrationRequired",
+      "msg": "Config Migration Required."
+    },
+    {
+      "code": 6042,
+      "name": "configAlreadyMigrated",
+      "msg": "Config Already Migrated."
+    },
+    {
+      "code": 6043,
+      "name": "invalidConfigBatch",
+      "msg": "Invalid Config Batch."
+    },
+    {
+      "code": 6044,
+      "name": "proposalExpired",
+      "msg": "Proposal Expired."
+    },
+    {
+      "code": 6045,
+      "name": "feeStatsRequired",
+      "msg": "Fee Stats Required."
+    },
+    {
+      "code": 6046,
+      "name": "configFieldAdminOnly",
+      "msg": "Config Field Admin Only."
+    },
+    {
+      "code": 6047,
+      "name": "pendingAdminExists",
+      "msg": "Pending Admin Exists."
+    },
+    {
+      "code": 6048,
+      "name": "invalidFeeInstructionIndex",
+      "msg": "Invalid Fee Instruction Index."
+    },
+    {
+      "code": 6049,
+      "name": "feeWaiverRequired",
+      "msg": "Fee Waiver Required."
+    },
+    {
+      "code": 6050,
+      "name": "programManagerRequired",
+      "msg": "Program Manager Required."
     }
   ],
-  "errors": [
+  "types": [
+    {
+      "name": "addAdminIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "addFeeScheduleIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeInstructionIndex",
+            "type": "u64"
+          },
+          {
+            "name": "feeSchedule",
+            "type": {
+              "defined": {
+                "name": "feeSchedule"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "addAdmin",
+            "fields": [
+              "pubkey"
+            ]
+          },
+          {
+            "name": "removeAdmin",
+            "fields": [
+              "pubkey"
+            ]
+          },
+          {
+            "name": "setApprovalThreshold",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "setMinUpdateDelay",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "updateGlobalConfig",
+            "fields": [
+              {
+                "vec": {
+                  "defined": {
+                    "name": "feeWallet"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "addProgramManager",
+            "fields": [
+              {
+                "name": "program",
+                "type": "pubkey"
+              },
+              {
+                "name": "manager",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "removeProgramManager",
+            "fields": [
+              {
+                "name": "program",
+                "type": "pubkey"
+              },
+              {
+                "name": "manager",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "createConfig",
+            "fields": [
+              {
+                "name": "program",
+                "type": "pubkey"
+              },
+              {
+                "name": "payload",
+                "type": {
+                  "defined": {
+                    "name": "createConfigIx"
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "admins",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvalThreshold",
+            "type": "u8"
+          },
+          {
+            "name": "minUpdateDelay",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "closeConfigIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeInstructionIndex",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "program",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "isUsingGlobalFeeWallets",
+            "type": "bool"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          },
+          {
+            "name": "feeInstructionName",
+            "type": "string"
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "feeMode",
+            "type": {
+              "defined": {
+                "name": "feeMode"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "remainderPolicy",
+            "type": {
+              "defined": {
+                "name": "remainderPolicy"
+              }
+            }
+          },
+          {
+            "name": "remainderTreasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "isInactive",
+            "type": "bool"
+          },
+          {
+            "name": "inactiveFeePolicy",
+            "type": {
+              "defined": {
+                "name": "inactiveFeePolicy"
+              }
+            }
+          },
+          {
+            "name": "isAccrualMode",
+            "type": "bool"
+          },
+          {
+            "name": "feeSchedules",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeSchedule"
+                }
+              }
+            }
+          },
+          {
+            "name": "platformSharePercent",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "hasFeeStats",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "update",
+            "fields": [
+              {
+                "defined": {
+                  "name": "updateConfigIx"
+                }
+              }
+            ]
+          },
+          {
+            "name": "setActive",
+            "fields": [
+              {
+                "name": "isActive",
+                "type": "bool"
+              },
+              {
+                "name": "inactiveFeePolicy",
+                "type": {
+                  "defined": {
+                    "name": "inactiveFeePolicy"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "addFeeSchedule",
+            "fields": [
+              {
+                "defined": {
+                  "name": "feeSchedule"
+                }
+              }
+            ]
+          },
+          {
+            "name": "removeFeeSchedule",
+            "fields": [
+              {
+                "name": "startTs",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "createFeeWaiver",
+            "fields": [
+              {
+                "name": "payer",
+                "type": "pubkey"
+              },
+              {
+                "name": "discountPercent",
+                "type": "u64"
+              },
+              {
+                "name": "expiresAt",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "updateFeeWaiver",
+            "fields": [
+              {
+                "name": "payer",
+                "type": "pubkey"
+              },
+              {
+                "name": "discountPercent",
+                "type": "u64"
+              },
+              {
+                "name": "expiresAt",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "removeFeeWaiver",
+            "fields": [
+              {
+                "name": "payer",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "close"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "program",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "rentReceiver",
+            "type": "pubkey"
+          },
+          {
+            "name": "closedAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "program",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "values",
+            "type": {
+              "defined": {
+                "name": "configValues"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "program",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "oldVersion",
+            "type": "u8"
+          },
+          {
+            "name": "newVersion",
+            "type": "u8"
+          },
+          {
+            "name": "migratedAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "configAction"
+              }
+            }
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdateCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "effectiveAt",
+            "type": "u64"
+          },
+          {
+            "name": "cancelledAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdateScheduled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "program",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "configAction"
+              }
+            }
+          },
+          {
+            "name": "oldValues",
+            "type": {
+              "defined": {
+                "name": "configValues"
+              }
+            }
+          },
+          {
+            "name": "newValues",
+            "type": {
+              "defined": {
+                "name": "configValues"
+              }
+            }
+          },
+          {
+            "name": "effectiveAt",
+            "type": "u64"
+          },
+          {
+            "name": "scheduledAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "program",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "oldValues",
+            "type": {
+              "defined": {
+                "name": "configValues"
+              }
+            }
+          },
+          {
+            "name": "newValues",
+            "type": {
+              "defined": {
+                "name": "configValues"
+              }
+            }
+          },
+          {
+            "name": "updatedAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configValues",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isUsingGlobalFeeWallets",
+            "type": "bool"
+          },
+          {
+            "name": "feeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeInstructionName",
+            "type": "string"
+          },
+          {
+            "name": "feeMode",
+            "type": {
+              "defined": {
+                "name": "feeMode"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "remainderPolicy",
+            "type": {
+              "defined": {
+                "name": "remainderPolicy"
+              }
+            }
+          },
+          {
+            "name": "remainderTreasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "isInactive",
+            "type": "bool"
+          },
+          {
+            "name": "inactiveFeePolicy",
+            "type": {
+              "defined": {
+                "name": "inactiveFeePolicy"
+              }
+            }
+          },
+          {
+            "name": "isAccrualMode",
+            "type": "bool"
+          },
+          {
+            "name": "feeSchedules",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeSchedule"
+                }
+              }
+            }
+          },
+          {
+            "name": "platformSharePercent",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "createAdminProposalIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "createConfigIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeInstructionIndex",
+            "type": "u64"
+          },
+          {
+            "name": "isUsingGlobalFeeWallets",
+            "type": "bool"
+          },
+          {
+            "name": "feeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeInstructionName",
+            "type": "string"
+          },
+          {
+            "name": "feeMode",
+            "type": {
+              "defined": {
+                "name": "feeMode"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "remainderPolicy",
+            "type": {
+              "defined": {
+                "name": "remainderPolicy"
+              }
+            }
+          },
+          {
+            "name": "remainderTreasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "isAccrualMode",
+            "type": "bool"
+          },
+          {
+            "name": "platformSharePercent",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "createConfigsBatchIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "createConfigIx"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "createFeeWaiverIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "discountPercent",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "createGlobalConfigIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "createProposalIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "configAction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeBalance",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "flat"
+          },
+          {
+            "name": "bps"
+          },
+          {
+            "name": "flatPlusBps"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeOverride",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "amount",
+            "fields": [
+              "u64"
+            ]
+          },
+          {
+            "name": "percent",
+            "fields": [
+              "u64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseAmount",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "discountPercent",
+            "type": "u64"
+          },
+          {
+            "name": "feeSchedule",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "feeSchedule"
+                }
+              }
+            }
+          },
+          {
+            "name": "platformFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeTransfer"
+                }
+              }
+            }
+          },
+          {
+            "name": "treasury",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "feeTransfer"
+                }
+              }
+            }
+          },
+          {
+            "name": "isWaived",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startTs",
+            "type": "u64"
+          },
+          {
+            "name": "endTs",
+            "type": "u64"
+          },
+          {
+            "name": "feeOverride",
+            "type": {
+              "defined": {
+                "name": "feeOverride"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "totalCalls",
+            "type": "u64"
+          },
+          {
+            "name": "totalWaivedCalls",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "walletTotals",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "walletFeeTotal"
+                }
+              }
+            }
+          },
+          {
+            "name": "untrackedFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lastChargedAt",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTransfer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "balances",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeBalance"
+                }
+              }
+            }
+          },
+          {
+            "name": "totalAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "totalClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
     {
-      "code": 6000,
-      "name": "invalidAuthority",
-      "msg": "Invalid Authority."
+      "name": "feeWaiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "discountPercent",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "invalidInstruction",
-      "msg": "Invalid Instruction."
+      "name": "feeWallet",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "pubkey"
+          },
+          {
+            "name": "feePercent",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidFeeWallet",
-      "msg": "Invalid Fee Wallet."
+      "name": "feesClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "claimedAt",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "invalidRemainingAccounts",
-      "msg": "Invalid Remaining Accounts."
-    }
-  ],
-  "types": [
+      "name": "feesTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "targetProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeInstructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "baseAmount",
+            "type": "u64"
+          },
+          {
+            "name": "totalFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "discountPercent",
+            "type": "u64"
+          },
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "transfers",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeTransfer"
+                }
+              }
+            }
+          },
+          {
+            "name": "isWaived",
+            "type": "bool"
+          },
+          {
+            "name": "isAccrued",
+            "type": "bool"
+          },
+          {
+            "name": "transferredAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "feeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u128",
+                2
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalConfigUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldFeeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          },
+          {
+            "name": "newFeeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          },
+          {
+            "name": "updatedAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inactiveFeePolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "waive"
+          },
+          {
+            "name": "reject"
+          }
+        ]
+      }
+    },
+    {
+      "name": "migrateConfigIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeInstructionIndex",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
-      "name": "config",
+      "name": "pendingConfigUpdate",
       "type": {
         "kind": "struct",
         "fields": [
@@ -261,34 +6181,61 @@ export type FeeGovernanceHub = {
             "type": "u8"
           },
           {
-            "name": "program",
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "feeInstructionIndex",
-            "type": "u8"
+            "name": "scheduledBy",
+            "type": "pubkey"
           },
           {
-            "name": "isUsingGlobalFeeWallets",
-            "type": "bool"
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "configAction"
+              }
+            }
           },
           {
-            "name": "feeAmount",
+            "name": "effectiveAt",
             "type": "u64"
           },
           {
-            "name": "feeWallets",
+            "name": "createdAt",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "feeWallet"
-                }
-              }
+              "array": [
+                "u128",
+                2
+              ]
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "programManager",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "feeInstructionName",
-            "type": "string"
+            "name": "program",
+            "type": "pubkey"
+          },
+          {
+            "name": "manager",
+            "type": "pubkey"
+          },
+          {
+            "name": "grantedBy",
+            "type": "pubkey"
           },
           {
             "name": "createdAt",
@@ -307,7 +6254,19 @@ export type FeeGovernanceHub = {
       }
     },
     {
-      "name": "createConfigIx",
+      "name": "proposeAdminIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "quoteFeesIx",
       "type": {
         "kind": "struct",
         "fields": [
@@ -316,47 +6275,124 @@ export type FeeGovernanceHub = {
             "type": "u64"
           },
           {
-            "name": "isUsingGlobalFeeWallets",
-            "type": "bool"
+            "name": "baseAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "remainderPolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "firstWallet"
           },
           {
-            "name": "feeWallets",
+            "name": "largestShare"
+          },
+          {
+            "name": "treasury"
+          }
+        ]
+      }
+    },
+    {
+      "name": "removeAdminIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "removeFeeScheduleIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeInstructionIndex",
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "scheduleConfigUpdateIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeInstructionIndex",
+            "type": "u64"
+          },
+          {
+            "name": "action",
             "type": {
-              "array": [
-                {
-                  "defined": {
-                    "name": "feeWallet"
-                  }
-                },
-                3
-              ]
+              "defined": {
+                "name": "configAction"
+              }
             }
           },
           {
-            "name": "feeAmount",
+            "name": "effectiveAt",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "setApprovalThresholdIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "feeInstructionName",
-            "type": "string"
+            "name": "approvalThreshold",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "feeWallet",
-      "repr": {
-        "kind": "c"
-      },
+      "name": "setConfigActiveIx",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "address",
-            "type": "pubkey"
+            "name": "feeInstructionIndex",
+            "type": "u64"
           },
           {
-            "name": "feePercent",
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "inactiveFeePolicy",
+            "type": {
+              "defined": {
+                "name": "inactiveFeePolicy"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "setMinUpdateDelayIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minUpdateDelay",
             "type": "u64"
           }
         ]
@@ -370,6 +6406,10 @@ export type FeeGovernanceHub = {
           {
             "name": "feeInstructionIndex",
             "type": "u64"
+          },
+          {
+            "name": "baseAmount",
+            "type": "u64"
           }
         ]
       }
@@ -390,14 +6430,11 @@ export type FeeGovernanceHub = {
           {
             "name": "feeWallets",
             "type": {
-              "array": [
-                {
-                  "defined": {
-                    "name": "feeWallet"
-                  }
-                },
-                3
-              ]
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
             }
           },
           {
@@ -407,6 +6444,121 @@ export type FeeGovernanceHub = {
           {
             "name": "feeInstructionName",
             "type": "string"
+          },
+          {
+            "name": "feeMode",
+            "type": {
+              "defined": {
+                "name": "feeMode"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "minFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "remainderPolicy",
+            "type": {
+              "defined": {
+                "name": "remainderPolicy"
+              }
+            }
+          },
+          {
+            "name": "remainderTreasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "isAccrualMode",
+            "type": "bool"
+          },
+          {
+            "name": "platformSharePercent",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateConfigsBatchIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "updateConfigIx"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateFeeWaiverIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "discountPercent",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateGlobalConfigIx",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeWallets",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeWallet"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "walletFeeTotal",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
           }
         ]
       }
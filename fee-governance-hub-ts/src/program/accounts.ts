@@ -1,14 +1,35 @@
 import * as anchor from "@coral-xyz/anchor";
 import { MemcmpFilter, SystemProgram } from "@solana/web3.js";
-import { InstructionFeeConfig } from "./types/InstructionFeeConfig";
+import {
+  FeeMode,
+  FeeWallet,
+  InstructionFeeConfig,
+  RemainderPolicy,
+} from "./types/InstructionFeeConfig";
 import { FeeGovernanceHub } from "../idl/fee_governance_hub";
 import {
   CONFIG_TAG,
   getFeeGovernanceHubProgram,
+  getGlobalConfigKey,
   PERCENT_DENOMINATOR,
 } from "./constants";
 import * as FeeGovernanceHubIdl from "../idl/fee_governance_hub.json";
 
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+const parseFeeWallets = (rawFeeWallets: any[]): FeeWallet[] =>
+  rawFeeWallets
+    .filter(
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      (wallet: any) =>
+        wallet.address.toBase58() !== SystemProgram.programId.toBase58() ||
+        !wallet.feePercent.isZero()
+    )
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    .map((wallet: any) => ({
+      address: wallet.address.toString(),
+      feePercent: wallet.feePercent.toNumber() / PERCENT_DENOMINATOR,
+    }));
+
 export const parseInstructionFeeConfig = (
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
   rawInstructionFeeConfig: any,
@@ -20,19 +41,24 @@ export const parseInstructionFeeConfig = (
     program: rawInstructionFeeConfig.program.toString(),
     feeInstructionIndex: rawInstructionFeeConfig.feeInstructionIndex,
     isUsingGlobalFeeWallets: rawInstructionFeeConfig.isUsingGlobalFeeWallets,
-    feeWallets: rawInstructionFeeConfig.feeWallets
-      .filter(
-        // eslint-disable-next-line @typescript-eslint/no-explicit-any
-        (wallet: any) => wallet.address !== SystemProgram.programId.toBase58()
-      )
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      .map((wallet: any) => ({
-        address: wallet.address.toString(),
-        feePercent: wallet.feePercent.toNumber() / PERCENT_DENOMINATOR,
-      })),
+    feeWallets: parseFeeWallets(rawInstructionFeeConfig.feeWallets),
     feeAmount: rawInstructionFeeConfig.feeAmount,
     feeInstructionName: rawInstructionFeeConfig.feeInstructionName.toString(),
     createdAt: rawInstructionFeeConfig.createdAt,
+    feeMode: Object.keys(rawInstructionFeeConfig.feeMode)[0] as FeeMode,
+    feeBps: rawInstructionFeeConfig.feeBps,
+    minFeeAmount: rawInstructionFeeConfig.minFeeAmount.toNumber(),
+    maxFeeAmount: rawInstructionFeeConfig.maxFeeAmount.toNumber(),
+    feeMint: rawInstructionFeeConfig.feeMint.toString(),
+    remainderPolicy: Object.keys(
+      rawInstructionFeeConfig.remainderPolicy
+    )[0] as RemainderPolicy,
+    remainderTreasury: rawInstructionFeeConfig.remainderTreasury.toString(),
+    isAccrualMode: rawInstructionFeeConfig.isAccrualMode,
+    platformSharePercent:
+      rawInstructionFeeConfig.platformSharePercent.toNumber() /
+      PERCENT_DENOMINATOR,
+    version: rawInstructionFeeConfig.version,
   };
 
   return instructionFeeConfig;
@@ -99,3 +125,15 @@ export const getFeeConfigsByProgram = async (
     parseInstructionFeeConfig(config.account, config.publicKey)
   );
 };
+
+// fee wallets of the GlobalConfig, for configs using the global fee wallets or with a platform share
+export const getGlobalFeeWallets = async (
+  program: anchor.Program<FeeGovernanceHub>
+): Promise<FeeWallet[]> => {
+  const globalConfig = await program.account.globalConfig.fetchNullable(
+    getGlobalConfigKey(program.programId)
+  );
+  if (!globalConfig) return [];
+
+  return parseFeeWallets(globalConfig.feeWallets);
+};
@@ -6,6 +6,8 @@ import { PublicKey } from "@solana/web3.js";
 
 // constants for seeds
 export const CONFIG_TAG = Buffer.from("CONFIG_TAG");
+export const GLOBAL_CONFIG_TAG = Buffer.from("GLOBAL_CONFIG_TAG");
+export const ADMIN_REGISTRY_TAG = Buffer.from("ADMIN_REGISTRY_TAG");
 
 export const getFeeGovernanceHubProgram = (
   connection: anchor.web3.Connection = new anchor.web3.Connection(
@@ -29,14 +31,15 @@ export const getFeeGovernanceHubProgram = (
 
 export const PROGRAM_ID = new PublicKey(FeeGovernanceHubIdl.address);
 
-export const MAX_FEE_WALLETS_LEN = 3;
+export const MAX_FEE_WALLETS_LEN = 16;
 export const MAX_FEE_INSTRUCTION_NAME_LEN = 30;
+// reserved for the program's default config, see createDefaultFeeConfig
+export const DEFAULT_FEE_INSTRUCTION_INDEX = 255;
 
 export const PERCENT_DENOMINATOR = 1000;
 
-export const GLOBAL_FEE_WALLETS = [
-  {
-    address: new PublicKey("ArpaDqpkJpKfxLP7WoFvYMbkj33C1PAHcy8tyrxFpgrc"),
-    feePercent: 1000,
-  },
-];
+export const getAdminRegistryKey = (programId: PublicKey = PROGRAM_ID) =>
+  PublicKey.findProgramAddressSync([ADMIN_REGISTRY_TAG], programId)[0];
+
+export const getGlobalConfigKey = (programId: PublicKey = PROGRAM_ID) =>
+  PublicKey.findProgramAddressSync([GLOBAL_CONFIG_TAG], programId)[0];
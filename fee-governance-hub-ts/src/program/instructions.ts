@@ -1,24 +1,40 @@
 import * as anchor from "@coral-xyz/anchor";
 import { FeeGovernanceHub } from "../idl/fee_governance_hub";
-import { FeeWallet, InstructionFeeConfig } from "./types";
 import {
-  GLOBAL_FEE_WALLETS,
+  DEFAULT_FEE_CONFIG_OPTIONS,
+  FeeConfigOptions,
+  FeeWallet,
+  InstructionFeeConfig,
+} from "./types";
+import {
+  DEFAULT_FEE_INSTRUCTION_INDEX,
+  getAdminRegistryKey,
   MAX_FEE_INSTRUCTION_NAME_LEN,
   MAX_FEE_WALLETS_LEN,
   PERCENT_DENOMINATOR,
 } from "./constants";
 import { SYSTEM_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/native/system";
 
-export const createFeeConfig = async (
-  authority: anchor.web3.PublicKey,
-  targetProgram: anchor.web3.PublicKey,
+// create_config/update_config payload
+const getFeeConfigIx = (
   feeInstructionIndex: number,
   isUsingGlobalFeeWallets: boolean,
   feeWallets: FeeWallet[],
   feeAmount: number,
   feeInstructionName: string,
-  program: anchor.Program<FeeGovernanceHub>
+  options: Partial<FeeConfigOptions>
 ) => {
+  if (
+    !Number.isInteger(feeInstructionIndex) ||
+    feeInstructionIndex < 0 ||
+    feeInstructionIndex > DEFAULT_FEE_INSTRUCTION_INDEX
+  ) {
+    throw new Error(
+      "Fee instruction index must be between 0 and " +
+        DEFAULT_FEE_INSTRUCTION_INDEX
+    );
+  }
+
   if (feeWallets.length > MAX_FEE_WALLETS_LEN) {
     throw new Error(
       "Fee wallets length must be less than or equal to " + MAX_FEE_WALLETS_LEN
@@ -32,19 +48,91 @@ export const createFeeConfig = async (
     );
   }
 
+  const feeConfigOptions = { ...DEFAULT_FEE_CONFIG_OPTIONS, ...options };
+
+  return {
+    feeInstructionIndex: new anchor.BN(feeInstructionIndex),
+    isUsingGlobalFeeWallets,
+    feeWallets: feeWallets.map((wallet) => ({
+      address: new anchor.web3.PublicKey(wallet.address),
+      feePercent: new anchor.BN(wallet.feePercent * PERCENT_DENOMINATOR),
+    })),
+    feeAmount: new anchor.BN(feeAmount),
+    feeInstructionName,
+    feeMode: { [feeConfigOptions.feeMode]: {} },
+    feeBps: feeConfigOptions.feeBps,
+    minFeeAmount: new anchor.BN(feeConfigOptions.minFeeAmount),
+    maxFeeAmount: new anchor.BN(feeConfigOptions.maxFeeAmount),
+    feeMint: new anchor.web3.PublicKey(feeConfigOptions.feeMint),
+    remainderPolicy: { [feeConfigOptions.remainderPolicy]: {} },
+    remainderTreasury: new anchor.web3.PublicKey(
+      feeConfigOptions.remainderTreasury
+    ),
+    isAccrualMode: feeConfigOptions.isAccrualMode,
+    platformSharePercent: new anchor.BN(
+      feeConfigOptions.platformSharePercent * PERCENT_DENOMINATOR
+    ),
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  } as any;
+};
+
+export const createFeeConfig = async (
+  authority: anchor.web3.PublicKey,
+  targetProgram: anchor.web3.PublicKey,
+  feeInstructionIndex: number,
+  isUsingGlobalFeeWallets: boolean,
+  feeWallets: FeeWallet[],
+  feeAmount: number,
+  feeInstructionName: string,
+  program: anchor.Program<FeeGovernanceHub>,
+  options: Partial<FeeConfigOptions> = {}
+) => {
   const ix = await program.methods
-    .createConfig({
-      feeInstructionIndex: new anchor.BN(feeInstructionIndex),
-      isUsingGlobalFeeWallets,
-      feeWallets: feeWallets.map((wallet) => ({
-        address: new anchor.web3.PublicKey(wallet.address),
-        feePercent: new anchor.BN(wallet.feePercent * PERCENT_DENOMINATOR),
-      })),
-      feeAmount: new anchor.BN(feeAmount),
-      feeInstructionName,
+    .createConfig(
+      getFeeConfigIx(
+        feeInstructionIndex,
+        isUsingGlobalFeeWallets,
+        feeWallets,
+        feeAmount,
+        feeInstructionName,
+        options
+      )
+    )
+    .accountsPartial({
+      authority,
+      adminRegistry: getAdminRegistryKey(program.programId),
+      targetProgram,
     })
-    .accounts({
+    .instruction();
+
+  return ix;
+};
+
+// default config of targetProgram, used by its instructions without a config of their own
+export const createDefaultFeeConfig = async (
+  authority: anchor.web3.PublicKey,
+  targetProgram: anchor.web3.PublicKey,
+  isUsingGlobalFeeWallets: boolean,
+  feeWallets: FeeWallet[],
+  feeAmount: number,
+  feeInstructionName: string,
+  program: anchor.Program<FeeGovernanceHub>,
+  options: Partial<FeeConfigOptions> = {}
+) => {
+  const ix = await program.methods
+    .createDefaultConfig(
+      getFeeConfigIx(
+        DEFAULT_FEE_INSTRUCTION_INDEX,
+        isUsingGlobalFeeWallets,
+        feeWallets,
+        feeAmount,
+        feeInstructionName,
+        options
+      )
+    )
+    .accountsPartial({
       authority,
+      adminRegistry: getAdminRegistryKey(program.programId),
       targetProgram,
     })
     .instruction();
@@ -60,34 +148,23 @@ export const updateFeeConfig = async (
   feeWallets: FeeWallet[],
   feeAmount: number,
   feeInstructionName: string,
-  program: anchor.Program<FeeGovernanceHub>
+  program: anchor.Program<FeeGovernanceHub>,
+  options: Partial<FeeConfigOptions> = {}
 ) => {
-  if (feeWallets.length > MAX_FEE_WALLETS_LEN) {
-    throw new Error(
-      "Fee wallets length must be less than or equal to " + MAX_FEE_WALLETS_LEN
-    );
-  }
-
-  if (feeInstructionName.length > MAX_FEE_INSTRUCTION_NAME_LEN) {
-    throw new Error(
-      "Fee instruction name must be less than or equal to " +
-        MAX_FEE_INSTRUCTION_NAME_LEN
-    );
-  }
-
   const ix = await program.methods
-    .updateConfig({
-      feeInstructionIndex: new anchor.BN(feeInstructionIndex),
-      isUsingGlobalFeeWallets,
-      feeWallets: feeWallets.map((wallet) => ({
-        address: new anchor.web3.PublicKey(wallet.address),
-        feePercent: new anchor.BN(wallet.feePercent * PERCENT_DENOMINATOR),
-      })),
-      feeAmount: new anchor.BN(feeAmount),
-      feeInstructionName,
-    })
-    .accounts({
+    .updateConfig(
+      getFeeConfigIx(
+        feeInstructionIndex,
+        isUsingGlobalFeeWallets,
+        feeWallets,
+        feeAmount,
+        feeInstructionName,
+        options
+      )
+    )
+    .accountsPartial({
       authority,
+      adminRegistry: getAdminRegistryKey(program.programId),
       targetProgram,
     })
     .instruction();
@@ -95,26 +172,43 @@ export const updateFeeConfig = async (
   return ix;
 };
 
+// Fee wallets transfer_fees pays for a SOL fee config, in remaining accounts
+// order. globalFeeWallets are the fee wallets of the GlobalConfig
+// (getGlobalFeeWallets), needed when the config uses them or has a platform share.
 export const getRemainingAccountsForFees = (
-  config: InstructionFeeConfig
+  config: InstructionFeeConfig,
+  globalFeeWallets: FeeWallet[] = []
 ): {
   pubkey: anchor.web3.PublicKey;
   isWritable: boolean;
   isSigner: boolean;
 }[] => {
-  if (config.isUsingGlobalFeeWallets) {
-    return GLOBAL_FEE_WALLETS.map((wallet) => ({
-      pubkey: new anchor.web3.PublicKey(wallet.address),
-      isWritable: true,
-      isSigner: false,
-    }));
+  if (config.isAccrualMode) {
+    return []; // fees go to the config's fee vault
+  }
+
+  const platformFeeWallets =
+    !config.isUsingGlobalFeeWallets && config.platformSharePercent > 0
+      ? globalFeeWallets
+      : [];
+  const feeWallets = config.isUsingGlobalFeeWallets
+    ? globalFeeWallets
+    : config.feeWallets;
+
+  const addresses = [...platformFeeWallets, ...feeWallets]
+    .filter(
+      (wallet) =>
+        wallet.address !== SYSTEM_PROGRAM_ID.toBase58() ||
+        wallet.feePercent > 0
+    )
+    .map((wallet) => wallet.address);
+  if (config.remainderPolicy === "treasury") {
+    addresses.push(config.remainderTreasury);
   }
 
-  return config.feeWallets
-    .filter((wallet) => wallet.address !== SYSTEM_PROGRAM_ID.toBase58())
-    .map((wallet) => ({
-      pubkey: new anchor.web3.PublicKey(wallet.address),
-      isWritable: true,
-      isSigner: false,
-    }));
+  return addresses.map((address) => ({
+    pubkey: new anchor.web3.PublicKey(address),
+    isWritable: true,
+    isSigner: false,
+  }));
 };
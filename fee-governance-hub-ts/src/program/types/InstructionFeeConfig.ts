@@ -6,7 +6,36 @@ export interface FeeWallet {
   feePercent: number;
 }
 
-export interface InstructionFeeConfig {
+export type FeeMode = "flat" | "bps" | "flatPlusBps";
+
+export type RemainderPolicy = "firstWallet" | "largestShare" | "treasury";
+
+// fee settings of create_config/update_config beyond the flat fee, defaults keep the flat SOL fee
+export interface FeeConfigOptions {
+  feeMode: FeeMode;
+  feeBps: number; // out of 10000
+  minFeeAmount: number; // flatPlusBps only
+  maxFeeAmount: number; // flatPlusBps only, 0 for no cap
+  feeMint: string; // SYSTEM_PROGRAM_ID for SOL
+  remainderPolicy: RemainderPolicy;
+  remainderTreasury: string; // treasury policy only
+  isAccrualMode: boolean;
+  platformSharePercent: number; // of the fee to the global fee wallets, 0.05 for 5%
+}
+
+export const DEFAULT_FEE_CONFIG_OPTIONS: FeeConfigOptions = {
+  feeMode: "flat",
+  feeBps: 0,
+  minFeeAmount: 0,
+  maxFeeAmount: 0,
+  feeMint: SYSTEM_PROGRAM_ID.toBase58(),
+  remainderPolicy: "firstWallet",
+  remainderTreasury: SYSTEM_PROGRAM_ID.toBase58(),
+  isAccrualMode: false,
+  platformSharePercent: 0,
+};
+
+export interface InstructionFeeConfig extends FeeConfigOptions {
   key: string;
   bump: number;
   program: string;
@@ -16,4 +45,5 @@ export interface InstructionFeeConfig {
   feeAmount: number;
   feeInstructionName: string;
   createdAt: number;
+  version: number; // configs of older versions need migrate_config
 }
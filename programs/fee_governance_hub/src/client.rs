@@ -0,0 +1,558 @@
+use {
+    crate::{constant::*, processor::*, state::*},
+    anchor_lang::{prelude::*, solana_program::instruction::Instruction, AccountDeserialize, InstructionData},
+    anchor_spl::associated_token::get_associated_token_address_with_program_id,
+};
+
+pub fn find_config_address(target_program: &Pubkey, fee_instruction_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_TAG, target_program.as_ref(), &fee_instruction_index.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_default_config_address(target_program: &Pubkey) -> (Pubkey, u8) {
+    find_config_address(target_program, DEFAULT_FEE_INSTRUCTION_INDEX)
+}
+
+pub fn find_global_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GLOBAL_CONFIG_TAG], &crate::ID)
+}
+
+pub fn find_admin_registry_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ADMIN_REGISTRY_TAG], &crate::ID)
+}
+
+pub fn find_fee_waiver_address(config: &Pubkey, payer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_WAIVER_TAG, config.as_ref(), payer.as_ref()], &crate::ID)
+}
+
+pub fn find_fee_vault_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_VAULT_TAG, config.as_ref()], &crate::ID)
+}
+
+pub fn find_fee_vault_token_account_address(fee_vault: &Pubkey, fee_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_VAULT_TAG, fee_vault.as_ref(), fee_mint.as_ref()], &crate::ID)
+}
+
+pub fn find_fee_stats_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_STATS_TAG, config.as_ref()], &crate::ID)
+}
+
+pub fn find_program_manager_address(target_program: &Pubkey, manager: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROGRAM_MANAGER_TAG, target_program.as_ref(), manager.as_ref()], &crate::ID)
+}
+
+pub fn find_config_proposal_address(config: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_PROPOSAL_TAG, config.as_ref(), &proposal_id.to_le_bytes()], &crate::ID)
+}
+
+pub fn find_pending_config_update_address(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PENDING_CONFIG_UPDATE_TAG, config.as_ref()], &crate::ID)
+}
+
+// signer PDA of target_program for transfer_fees, target_program signs for it with seeds [FEE_CALLER_TAG, &[bump]]
+pub fn find_fee_caller_address(target_program: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_CALLER_TAG], target_program)
+}
+
+// remaining accounts of create_configs_batch/update_configs_batch, in entry order
+pub fn config_batch_account_metas(target_program: &Pubkey, fee_instruction_indexes: &[u64]) -> Vec<AccountMeta> {
+    fee_instruction_indexes.iter()
+        .map(|fee_instruction_index| AccountMeta::new(find_config_address(target_program, *fee_instruction_index).0, false))
+        .collect()
+}
+
+pub fn create_config_instruction(authority: &Pubkey, target_program: &Pubkey, ix: CreateConfigIx) -> Instruction {
+    let accounts = crate::accounts::CreateConfigCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        config: find_config_address(target_program, ix.fee_instruction_index).0,
+        default_config: find_default_config_address(target_program).0,
+        target_program: *target_program,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::CreateConfig { ix }.data(),
+    }
+}
+
+pub fn update_config_instruction(authority: &Pubkey, target_program: &Pubkey, ix: UpdateConfigIx) -> Instruction {
+    let accounts = crate::accounts::UpdateConfigCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        config: find_config_address(target_program, ix.fee_instruction_index).0,
+        target_program: *target_program,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::UpdateConfig { ix }.data(),
+    }
+}
+
+pub fn create_proposal_instruction(authority: &Pubkey, target_program: &Pubkey, ix: CreateProposalIx) -> Instruction {
+    let (config, _) = find_config_address(target_program, ix.fee_instruction_index);
+    let accounts = crate::accounts::CreateProposalCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        config,
+        proposal: find_config_proposal_address(&config, ix.proposal_id).0,
+        target_program: *target_program,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::CreateProposal { ix }.data(),
+    }
+}
+
+pub fn approve_proposal_instruction(authority: &Pubkey, config: &Pubkey, proposal_id: u64) -> Instruction {
+    let accounts = crate::accounts::ApproveProposalCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        proposal: find_config_proposal_address(config, proposal_id).0,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::ApproveProposal {}.data(),
+    }
+}
+
+// fee_waiver is the FeeWaiver PDA of the action's payer, required by fee waiver actions
+pub fn execute_proposal_instruction(authority: &Pubkey, config: &Pubkey, proposal_id: u64, proposer: &Pubkey, fee_waiver: Option<Pubkey>) -> Instruction {
+    let accounts = crate::accounts::ExecuteProposalCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        config: *config,
+        proposal: find_config_proposal_address(config, proposal_id).0,
+        proposer: *proposer,
+        fee_waiver,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::ExecuteProposal {}.data(),
+    }
+}
+
+pub fn cancel_proposal_instruction(authority: &Pubkey, config: &Pubkey, proposal_id: u64, proposer: &Pubkey) -> Instruction {
+    let accounts = crate::accounts::CancelProposalCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        proposal: find_config_proposal_address(config, proposal_id).0,
+        proposer: *proposer,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::CancelProposal {}.data(),
+    }
+}
+
+// is_program_manager passes the authority's ProgramManager PDA, for updates scheduled by a program manager
+pub fn schedule_config_update_instruction(authority: &Pubkey, target_program: &Pubkey, ix: ScheduleConfigUpdateIx, is_program_manager: bool) -> Instruction {
+    let (config, _) = find_config_address(target_program, ix.fee_instruction_index);
+    let accounts = crate::accounts::ScheduleConfigUpdateCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        program_manager: is_program_manager.then(|| find_program_manager_address(target_program, authority).0),
+        config,
+        pending_update: find_pending_config_update_address(&config).0,
+        target_program: *target_program,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::ScheduleConfigUpdate { ix }.data(),
+    }
+}
+
+// scheduled_by and is_program_manager come from the PendingConfigUpdate, fee_waiver as in execute_proposal_instruction
+pub fn apply_pending_update_instruction(
+    authority: &Pubkey,
+    target_program: &Pubkey,
+    config: &Pubkey,
+    scheduled_by: &Pubkey,
+    is_program_manager: bool,
+    fee_waiver: Option<Pubkey>,
+) -> Instruction {
+    let accounts = crate::accounts::ApplyPendingUpdateCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        config: *config,
+        pending_update: find_pending_config_update_address(config).0,
+        scheduled_by: *scheduled_by,
+        program_manager: is_program_manager.then(|| find_program_manager_address(target_program, scheduled_by).0),
+        fee_waiver,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::ApplyPendingUpdate {}.data(),
+    }
+}
+
+pub fn cancel_pending_update_instruction(authority: &Pubkey, config: &Pubkey, scheduled_by: &Pubkey) -> Instruction {
+    let accounts = crate::accounts::CancelPendingUpdateCtx {
+        authority: *authority,
+        admin_registry: find_admin_registry_address().0,
+        pending_update: find_pending_config_update_address(config).0,
+        scheduled_by: *scheduled_by,
+    };
+
+    Instruction {
+        program_id: crate::ID,
+        accounts: accounts.to_account_metas(None),
+        data: crate::instruction::CancelPendingUpdate {}.data(),
+    }
+}
+
+fn fetch_account<T: AccountDeserialize, F: Fn(&Pubkey) -> Option<Vec<u8>>>(fetch: &F, address: &Pubkey) -> Result<Option<T>> {
+    match fetch(address) {
+        Some(data) if !data.is_empty() => Ok(Some(T::try_deserialize(&mut &data[..])?)),
+        _ => Ok(None),
+    }
+}
+
+// configs of legacy layouts are read too, check is_current_version before using them with the program
+pub fn fetch_config_account<F: Fn(&Pubkey) -> Option<Vec<u8>>>(fetch: &F, address: &Pubkey) -> Result<Option<Config>> {
+    match fetch(address) {
+        Some(data) if !data.is_empty() => Ok(Some(Config::try_deserialize_versioned(&data)?)),
+        _ => Ok(None),
+    }
+}
+
+// Config that transfer_fees uses for an instruction, with the accounts it depends on
+pub struct ResolvedConfig {
+    pub target_program: Pubkey,
+    pub address: Pubkey,
+    pub config: Config,
+    pub fee_instruction_config: Option<Pubkey>, // empty instruction config PDA, set when falling back to the default config
+    pub global_config: Option<GlobalConfig>, // set when the config pays the global fee wallets
+}
+
+// Fetches the config of fee_instruction_index of target_program, falling back to its default config.
+// fetch returns the data of an account, None if it doesn't exist. e.g. |address| rpc_client.get_account_data(address).ok()
+pub fn fetch_config<F: Fn(&Pubkey) -> Option<Vec<u8>>>(fetch: F, target_program: &Pubkey, fee_instruction_index: u64) -> Result<Option<ResolvedConfig>> {
+    let (instruction_config_address, _) = find_config_address(target_program, fee_instruction_index);
+    let (address, config, fee_instruction_config) = match fetch_config_account(&fetch, &instruction_config_address)? {
+        Some(config) => (instruction_config_address, config, None),
+        None => {
+            let (default_config_address, _) = find_default_config_address(target_program);
+            match fetch_config_account(&fetch, &default_config_address)? {
+                Some(config) => (default_config_address, config, Some(instruction_config_address)),
+                None => return Ok(None),
+            }
+        }
+    };
+
+    let global_config = if config.is_using_global_fee_wallets || config.has_platform_share() {
+        fetch_account::<GlobalConfig, _>(&fetch, &find_global_config_address().0)?
+    } else {
+        None
+    };
+
+    Ok(Some(ResolvedConfig {
+        target_program: *target_program,
+        address,
+        config,
+        fee_instruction_config,
+        global_config,
+    }))
+}
+
+impl ResolvedConfig {
+    // wallets transfer_fees pays, in remaining accounts order. The remainder treasury is last with the Treasury policy.
+    pub fn fee_wallet_addresses(&self) -> Result<Vec<Pubkey>> {
+        let fee_quote = self.config.quote_fees(self.global_config.as_ref(), None, 0, 0)?;
+
+        Ok(fee_quote.fee_wallets.iter()
+            .chain(fee_quote.treasury.iter())
+            .map(|fee_transfer| fee_transfer.wallet)
+            .collect())
+    }
+
+    pub fn fee_vault(&self) -> Option<Pubkey> {
+        self.config.is_accrual_mode.then(|| find_fee_vault_address(&self.address).0)
+    }
+
+    // required by transfer_fees once the config has one
+    pub fn fee_stats(&self) -> Option<Pubkey> {
+        self.config.has_fee_stats.then(|| find_fee_stats_address(&self.address).0)
+    }
+
+    pub fn fee_vault_token_account(&self) -> Option<Pubkey> {
+        self.fee_vault()
+            .filter(|_| self.config.is_token_fee())
+            .map(|fee_vault| find_fee_vault_token_account_address(&fee_vault, &self.config.fee_mint).0)
+    }
+
+    // Remaining accounts to append for transfer_fees: fee wallets(their associated token accounts for token fees)
+    // unless the config is in accrual mode, then the payer's FeeWaiver if it has one.
+    pub fn remaining_account_metas(&self, token_program: &Pubkey, fee_waiver: Option<Pubkey>) -> Result<Vec<AccountMeta>> {
+        let mut account_metas = Vec::new();
+
+        if !self.config.is_accrual_mode {
+            for wallet in self.fee_wallet_addresses()? {
+                let recipient = if self.config.is_token_fee() {
+                    get_associated_token_address_with_program_id(&wallet, &self.config.fee_mint, token_program)
+                } else {
+                    wallet
+                };
+                account_metas.push(AccountMeta::new(recipient, false));
+            }
+        }
+
+        if let Some(fee_waiver) = fee_waiver {
+            account_metas.push(AccountMeta::new_readonly(fee_waiver, false));
+        }
+
+        Ok(account_metas)
+    }
+
+    // Instruction to simulate for the FeeQuote of payer, returned in the transaction's return data
+    pub fn quote_fees_instruction(&self, payer: &Pubkey, fee_instruction_index: u64, base_amount: u64, has_fee_waiver: bool) -> Instruction {
+        let accounts = crate::accounts::QuoteFeesCtx {
+            config: self.address,
+            fee_instruction_config: self.fee_instruction_config,
+            global_config: self.global_config.as_ref().map(|_| find_global_config_address().0),
+            fee_waiver: has_fee_waiver.then(|| find_fee_waiver_address(&self.address, payer).0),
+            target_program: self.target_program,
+            payer: *payer,
+        };
+
+        Instruction {
+            program_id: crate::ID,
+            accounts: accounts.to_account_metas(None),
+            data: crate::instruction::QuoteFees {
+                ix: crate::processor::QuoteFeesIx { fee_instruction_index, base_amount },
+            }.data(),
+        }
+    }
+    // transfer_fees of payer with its remaining accounts. target_program CPIs it and signs for the fee_caller PDA.
+    pub fn transfer_fees_instruction(
+        &self,
+        payer: &Pubkey,
+        fee_instruction_index: u64,
+        base_amount: u64,
+        token_program: &Pubkey,
+        fee_waiver: Option<Pubkey>,
+    ) -> Result<Instruction> {
+        let is_token_fee = self.config.is_token_fee();
+        let accounts = crate::accounts::TransferFeesCtx {
+            payer: *payer,
+            config: self.address,
+            fee_instruction_config: self.fee_instruction_config,
+            global_config: self.global_config.as_ref().map(|_| find_global_config_address().0),
+            target_program: self.target_program,
+            fee_caller: find_fee_caller_address(&self.target_program).0,
+            fee_mint: is_token_fee.then_some(self.config.fee_mint),
+            payer_token_account: is_token_fee.then(|| get_associated_token_address_with_program_id(payer, &self.config.fee_mint, token_program)),
+            token_program: is_token_fee.then_some(*token_program),
+            fee_vault: self.fee_vault(),
+            fee_vault_token_account: self.fee_vault_token_account(),
+            fee_stats: self.fee_stats(),
+            system_program: System::id(),
+        };
+
+        let mut account_metas = accounts.to_account_metas(None);
+        account_metas.extend(self.remaining_account_metas(token_program, fee_waiver)?);
+
+        Ok(Instruction {
+            program_id: crate::ID,
+            accounts: account_metas,
+            data: crate::instruction::TransferFees {
+                ix: TransferFeesIx { fee_instruction_index, base_amount },
+            }.data(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, anchor_lang::AnchorDeserialize};
+
+    #[test]
+    fn transfer_fees_instruction_orders_accounts() {
+        let target_program = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let fee_mint = Pubkey::new_unique();
+        let fee_wallets = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let (default_config, _) = find_default_config_address(&target_program);
+        let (fee_instruction_config, _) = find_config_address(&target_program, 1);
+        let fee_waiver = find_fee_waiver_address(&default_config, &payer).0;
+        let resolved_config = ResolvedConfig {
+            target_program,
+            address: default_config,
+            config: Config {
+                version: CONFIG_VERSION,
+                fee_amount: 100,
+                fee_wallets: fee_wallets.iter()
+                    .map(|address| FeeWallet { address: *address, fee_percent: PERCENT_DENOMINATOR / 2 })
+                    .collect(),
+                fee_mint,
+                has_fee_stats: true,
+                ..Default::default()
+            },
+            fee_instruction_config: Some(fee_instruction_config),
+            global_config: None,
+        };
+
+        let instruction = resolved_config.transfer_fees_instruction(&payer, 1, 0, &anchor_spl::token::ID, Some(fee_waiver)).unwrap();
+
+        let mut account_metas = crate::accounts::TransferFeesCtx {
+            payer,
+            config: default_config,
+            fee_instruction_config: Some(fee_instruction_config),
+            global_config: None,
+            target_program,
+            fee_caller: find_fee_caller_address(&target_program).0,
+            fee_mint: Some(fee_mint),
+            payer_token_account: Some(get_associated_token_address_with_program_id(&payer, &fee_mint, &anchor_spl::token::ID)),
+            token_program: Some(anchor_spl::token::ID),
+            fee_vault: None,
+            fee_vault_token_account: None,
+            fee_stats: Some(find_fee_stats_address(&default_config).0),
+            system_program: System::id(),
+        }.to_account_metas(None);
+        account_metas.extend(fee_wallets.iter()
+            .map(|wallet| AccountMeta::new(get_associated_token_address_with_program_id(wallet, &fee_mint, &anchor_spl::token::ID), false)));
+        account_metas.push(AccountMeta::new_readonly(fee_waiver, false));
+
+        assert_eq!(instruction.accounts, account_metas);
+        assert_eq!(instruction.data, crate::instruction::TransferFees { ix: TransferFeesIx { fee_instruction_index: 1, base_amount: 0 } }.data());
+    }
+
+    #[test]
+    fn config_instructions_order_accounts() {
+        let authority = Pubkey::new_unique();
+        let target_program = Pubkey::new_unique();
+        let (config, _) = find_config_address(&target_program, 1);
+
+        let instruction = create_config_instruction(&authority, &target_program, CreateConfigIx { fee_instruction_index: 1, ..Default::default() });
+        assert_eq!(instruction.accounts, crate::accounts::CreateConfigCtx {
+            authority,
+            admin_registry: find_admin_registry_address().0,
+            config,
+            default_config: find_default_config_address(&target_program).0,
+            target_program,
+            system_program: System::id(),
+        }.to_account_metas(None));
+        assert_eq!(crate::instruction::CreateConfig::try_from_slice(&instruction.data[8..]).unwrap().ix.fee_instruction_index, 1);
+
+        let instruction = update_config_instruction(&authority, &target_program, UpdateConfigIx { fee_instruction_index: 1, ..Default::default() });
+        assert_eq!(instruction.accounts, crate::accounts::UpdateConfigCtx {
+            authority,
+            admin_registry: find_admin_registry_address().0,
+            config,
+            target_program,
+            system_program: System::id(),
+        }.to_account_metas(None));
+    }
+
+    #[test]
+    fn proposal_instructions_order_accounts() {
+        let authority = Pubkey::new_unique();
+        let proposer = Pubkey::new_unique();
+        let target_program = Pubkey::new_unique();
+        let (config, _) = find_config_address(&target_program, 1);
+        let (proposal, _) = find_config_proposal_address(&config, 7);
+        let fee_waiver = find_fee_waiver_address(&config, &Pubkey::new_unique()).0;
+
+        let instruction = create_proposal_instruction(&authority, &target_program, CreateProposalIx {
+            proposal_id: 7,
+            fee_instruction_index: 1,
+            action: ConfigAction::Close,
+        });
+        assert_eq!(instruction.accounts, crate::accounts::CreateProposalCtx {
+            authority,
+            admin_registry: find_admin_registry_address().0,
+            config,
+            proposal,
+            target_program,
+            system_program: System::id(),
+        }.to_account_metas(None));
+        assert!(matches!(crate::instruction::CreateProposal::try_from_slice(&instruction.data[8..]).unwrap().ix.action, ConfigAction::Close));
+
+        assert_eq!(approve_proposal_instruction(&authority, &config, 7).accounts, crate::accounts::ApproveProposalCtx {
+            authority,
+            admin_registry: find_admin_registry_address().0,
+            proposal,
+        }.to_account_metas(None));
+
+        assert_eq!(execute_proposal_instruction(&authority, &config, 7, &proposer, Some(fee_waiver)).accounts, crate::accounts::ExecuteProposalCtx {
+            authority,
+            admin_registry: find_admin_registry_address().0,
+            config,
+            proposal,
+            proposer,
+            fee_waiver: Some(fee_waiver),
+            system_program: System::id(),
+        }.to_account_metas(None));
+
+        assert_eq!(cancel_proposal_instruction(&authority, &config, 7, &proposer).accounts, crate::accounts::CancelProposalCtx {
+            authority,
+            admin_registry: find_admin_registry_address().0,
+            proposal,
+            proposer,
+        }.to_account_metas(None));
+    }
+
+    #[test]
+    fn pending_update_instructions_order_accounts() {
+        let manager = Pubkey::new_unique();
+        let target_program = Pubkey::new_unique();
+        let (config, _) = find_config_address(&target_program, 1);
+        let (pending_update, _) = find_pending_config_update_address(&config);
+        let (program_manager, _) = find_program_manager_address(&target_program, &manager);
+
+        let instruction = schedule_config_update_instruction(&manager, &target_program, ScheduleConfigUpdateIx {
+            fee_instruction_index: 1,
+            action: ConfigAction::Close,
+            effective_at: 100,
+        }, true);
+        assert_eq!(instruction.accounts, crate::accounts::ScheduleConfigUpdateCtx {
+            authority: manager,
+            admin_registry: find_admin_registry_address().0,
+            program_manager: Some(program_manager),
+            config,
+            pending_update,
+            target_program,
+            system_program: System::id(),
+        }.to_account_metas(None));
+        assert_eq!(crate::instruction::ScheduleConfigUpdate::try_from_slice(&instruction.data[8..]).unwrap().ix.effective_at, 100);
+
+        let authority = Pubkey::new_unique();
+        assert_eq!(apply_pending_update_instruction(&authority, &target_program, &config, &manager, true, None).accounts, crate::accounts::ApplyPendingUpdateCtx {
+            authority,
+            admin_registry: find_admin_registry_address().0,
+            config,
+            pending_update,
+            scheduled_by: manager,
+            program_manager: Some(program_manager),
+            fee_waiver: None,
+            system_program: System::id(),
+        }.to_account_metas(None));
+
+        assert_eq!(cancel_pending_update_instruction(&manager, &config, &manager).accounts, crate::accounts::CancelPendingUpdateCtx {
+            authority: manager,
+            admin_registry: find_admin_registry_address().0,
+            pending_update,
+            scheduled_by: manager,
+        }.to_account_metas(None));
+    }
+}
@@ -1,45 +1,36 @@
 /// constants for seeds
 pub const CONFIG_TAG:&[u8] = b"CONFIG_TAG";
+pub const GLOBAL_CONFIG_TAG:&[u8] = b"GLOBAL_CONFIG_TAG";
+pub const ADMIN_REGISTRY_TAG:&[u8] = b"ADMIN_REGISTRY_TAG";
+pub const CONFIG_PROPOSAL_TAG:&[u8] = b"CONFIG_PROPOSAL_TAG";
+pub const ADMIN_PROPOSAL_TAG:&[u8] = b"ADMIN_PROPOSAL_TAG";
+pub const PENDING_CONFIG_UPDATE_TAG:&[u8] = b"PENDING_CONFIG_UPDATE_TAG";
+pub const FEE_WAIVER_TAG:&[u8] = b"FEE_WAIVER_TAG";
+pub const FEE_CALLER_TAG:&[u8] = b"FEE_CALLER_TAG";
+pub const FEE_VAULT_TAG:&[u8] = b"FEE_VAULT_TAG";
+pub const FEE_STATS_TAG:&[u8] = b"FEE_STATS_TAG";
+pub const PROGRAM_MANAGER_TAG:&[u8] = b"PROGRAM_MANAGER_TAG";
 
-pub const MAX_FEE_WALLETS_LEN: usize = 3;
+pub const DEFAULT_FEE_INSTRUCTION_INDEX: u64 = u8::MAX as u64; // config used by instructions of a program without their own config
+pub const MAX_FEE_WALLETS_LEN: usize = 16;
+pub const CONFIG_VERSION: u8 = 2; // 1 is the layout before versioning, see ConfigV1
 pub const MAX_FEE_INSTRUCTION_NAME_LEN: usize = 30;
+pub const MAX_FEE_SCHEDULES_LEN: usize = 8;
+pub const MAX_CONFIG_BATCH_LEN: usize = 8; // configs per create_configs_batch/update_configs_batch
 pub const PERCENT_DENOMINATOR: u64 = 1000;
+pub const BPS_DENOMINATOR: u64 = 10000;
+pub const MAX_ADMINS_LEN: usize = 10;
+pub const PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60; // seconds a proposal stays executable after its timelock
+pub const MAX_FEE_VAULT_BALANCES_LEN: usize = 64; // a quote pays up to 33 wallets, the rest is room for wallets rotated out before claiming
+pub const MAX_FEE_STATS_WALLETS_LEN: usize = 32;
 
-/// constants for admin wallets
+/// constants for bootstrap admin wallets. Only used to initialize the admin registry.
 #[cfg(not(feature = "devnet"))]
 pub const ADMINS: [&str; 1] = [
   "F1tyGduCd9XLBSw2uAFycdNRUucyr2C7MiJ1Nifm2rZ8"
 ];
 
-#[cfg(not(feature = "devnet"))]
-pub const GLOBAL_FEE_WALLETS: [&str; 3] = [
-  "ArpaDqpkJpKfxLP7WoFvYMbkj33C1PAHcy8tyrxFpgrc",
-  "11111111111111111111111111111111",
-  "11111111111111111111111111111111"
-];
-
-#[cfg(not(feature = "devnet"))]
-pub const GLOBAL_FEE_WALLETS_FEE_PERCENT: [u64; 3] = [
-  1000,
-  0,
-  0
-];
-
 #[cfg(feature = "devnet")]
 pub const ADMINS: [&str; 1] = [
   "F1tyGduCd9XLBSw2uAFycdNRUucyr2C7MiJ1Nifm2rZ8"
 ];
-
-#[cfg(feature = "devnet")]
-pub const GLOBAL_FEE_WALLETS: [&str; 3] = [
-  "ArpaDqpkJpKfxLP7WoFvYMbkj33C1PAHcy8tyrxFpgrc",
-  "F1tyGduCd9XLBSw2uAFycdNRUucyr2C7MiJ1Nifm2rZ8",
-  "11111111111111111111111111111111"
-];
-
-#[cfg(feature = "devnet")]
-pub const GLOBAL_FEE_WALLETS_FEE_PERCENT: [u64; 3] = [
-  500,
-  500,
-  0
-];
@@ -14,4 +14,145 @@ pub enum ContractError {
 
     #[msg("Invalid Remaining Accounts.")]
     InvalidRemainingAccounts,
+
+    #[msg("Global Config Required.")]
+    GlobalConfigRequired,
+
+    #[msg("Admin Already Exists.")]
+    AdminAlreadyExists,
+
+    #[msg("Admin Not Found.")]
+    AdminNotFound,
+
+    #[msg("Exceed Max Num Admins.")]
+    ExceedMaxNumAdmins,
+
+    #[msg("Cannot Remove Last Admin.")]
+    CannotRemoveLastAdmin,
+
+    #[msg("Invalid Pending Admin.")]
+    InvalidPendingAdmin,
+
+    #[msg("Invalid Approval Threshold.")]
+    InvalidApprovalThreshold,
+
+    #[msg("Proposal Required.")]
+    ProposalRequired,
+
+    #[msg("Already Approved.")]
+    AlreadyApproved,
+
+    #[msg("Not Enough Approvals.")]
+    NotEnoughApprovals,
+
+    #[msg("Timelock Required.")]
+    TimelockRequired,
+
+    #[msg("Timelock Not Elapsed.")]
+    TimelockNotElapsed,
+
+    #[msg("Invalid Effective At.")]
+    InvalidEffectiveAt,
+
+    #[msg("Invalid Fee Mode.")]
+    InvalidFeeMode,
+
+    #[msg("MathOverflow.")]
+    MathOverflow,
+
+    #[msg("Token Accounts Required.")]
+    TokenAccountsRequired,
+
+    #[msg("Invalid Fee Mint.")]
+    InvalidFeeMint,
+
+    #[msg("Fee Percent Too High.")]
+    FeePercentTooHigh,
+
+    #[msg("Zero Percent Fee Wallet.")]
+    ZeroPercentFeeWallet,
+
+    #[msg("Duplicate Fee Wallet.")]
+    DuplicateFeeWallet,
+
+    #[msg("Invalid Fee Percent Sum.")]
+    InvalidFeePercentSum,
+
+    #[msg("Invalid Remainder Treasury.")]
+    InvalidRemainderTreasury,
+
+    #[msg("Exceed Max Num Fee Wallets.")]
+    ExceedMaxNumFeeWallets,
+
+    #[msg("Config Inactive.")]
+    ConfigInactive,
+
+    #[msg("Invalid Discount Percent.")]
+    InvalidDiscountPercent,
+
+    #[msg("Fee Vault Required.")]
+    FeeVaultRequired,
+
+    #[msg("Exceed Max Num Fee Balances.")]
+    ExceedMaxNumFeeBalances,
+
+    #[msg("Nothing To Claim.")]
+    NothingToClaim,
+
+    #[msg("Invalid Config.")]
+    InvalidConfig,
+
+    #[msg("Instruction Config Required.")]
+    InstructionConfigRequired,
+
+    #[msg("Instruction Config Exists.")]
+    InstructionConfigExists,
+
+    #[msg("Invalid Fee Schedule.")]
+    InvalidFeeSchedule,
+
+    #[msg("Fee Schedule Overlap.")]
+    FeeScheduleOverlap,
+
+    #[msg("Exceed Max Num Fee Schedules.")]
+    ExceedMaxNumFeeSchedules,
+
+    #[msg("Fee Schedule Not Found.")]
+    FeeScheduleNotFound,
+
+    #[msg("Global Fee Wallets Admin Only.")]
+    GlobalFeeWalletsAdminOnly,
+
+    #[msg("Invalid Platform Share Percent.")]
+    InvalidPlatformSharePercent,
+
+    #[msg("Config Migration Required.")]
+    ConfigMigrationRequired,
+
+    #[msg("Config Already Migrated.")]
+    ConfigAlreadyMigrated,
+
+    #[msg("Invalid Config Batch.")]
+    InvalidConfigBatch,
+
+    #[msg("Proposal Expired.")]
+    ProposalExpired,
+
+    #[msg("Fee Stats Required.")]
+    FeeStatsRequired,
+
+    #[msg("Config Field Admin Only.")]
+    ConfigFieldAdminOnly,
+
+    #[msg("Pending Admin Exists.")]
+    PendingAdminExists,
+
+    #[msg("Invalid Fee Instruction Index.")]
+    InvalidFeeInstructionIndex,
+
+    #[msg("Fee Waiver Required.")]
+    FeeWaiverRequired,
+
+    #[msg("Program Manager Required.")]
+    ProgramManagerRequired,
 }
@@ -0,0 +1,117 @@
+use {
+    crate::state::*,
+    anchor_lang::prelude::*,
+};
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct ConfigValues { // mutable fields of a Config
+    pub is_using_global_fee_wallets: bool,
+    pub fee_wallets: Vec<FeeWallet>,
+    pub fee_amount: u64,
+    pub fee_instruction_name: String,
+    pub fee_mode: FeeMode,
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+    pub fee_mint: Pubkey,
+    pub remainder_policy: RemainderPolicy,
+    pub remainder_treasury: Pubkey,
+    pub is_inactive: bool,
+    pub inactive_fee_policy: InactiveFeePolicy,
+    pub is_accrual_mode: bool,
+    pub fee_schedules: Vec<FeeSchedule>,
+    pub platform_share_percent: u64,
+}
+
+impl From<&Config> for ConfigValues {
+    fn from(config: &Config) -> Self {
+        ConfigValues {
+            is_using_global_fee_wallets: config.is_using_global_fee_wallets,
+            fee_wallets: config.fee_wallets.clone(),
+            fee_amount: config.fee_amount,
+            fee_instruction_name: config.fee_instruction_name.clone(),
+            fee_mode: config.fee_mode,
+            fee_bps: config.fee_bps,
+            min_fee_amount: config.min_fee_amount,
+            max_fee_amount: config.max_fee_amount,
+            fee_mint: config.fee_mint,
+            remainder_policy: config.remainder_policy,
+            remainder_treasury: config.remainder_treasury,
+            is_inactive: config.is_inactive,
+            inactive_fee_policy: config.inactive_fee_policy,
+            is_accrual_mode: config.is_accrual_mode,
+            fee_schedules: config.fee_schedules.clone(),
+            platform_share_percent: config.platform_share_percent,
+        }
+    }
+}
+
+#[event]
+pub struct ConfigCreated {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub program: Pubkey,
+    pub fee_instruction_index: u8,
+    pub values: ConfigValues,
+    pub created_at: u64,
+}
+
+#[event]
+pub struct ConfigUpdated { // update_config, execute_proposal, apply_pending_update, set_config_active and fee schedule changes
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub program: Pubkey,
+    pub fee_instruction_index: u8,
+    pub old_values: ConfigValues,
+    pub new_values: ConfigValues,
+    pub updated_at: u64,
+}
+
+#[event]
+pub struct ConfigUpdateScheduled { // schedule_config_update, applied by apply_pending_update from effective_at
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub program: Pubkey,
+    pub fee_instruction_index: u8,
+    pub action: ConfigAction, // fee waiver and Close actions leave the values as they are
+    pub old_values: ConfigValues,
+    pub new_values: ConfigValues,
+    pub effective_at: u64,
+    pub scheduled_at: u64,
+}
+
+#[event]
+pub struct ConfigUpdateCancelled {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub effective_at: u64,
+    pub cancelled_at: u64,
+}
+
+#[event]
+pub struct ConfigClosed {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub program: Pubkey,
+    pub fee_instruction_index: u8,
+    pub rent_receiver: Pubkey,
+    pub closed_at: u64,
+}
+
+#[event]
+pub struct GlobalConfigUpdated { // create_global_config and update_global_config
+    pub authority: Pubkey,
+    pub old_fee_wallets: Vec<FeeWallet>,
+    pub new_fee_wallets: Vec<FeeWallet>,
+    pub updated_at: u64,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub config: Pubkey,
+    pub program: Pubkey,
+    pub fee_instruction_index: u8,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub migrated_at: u64,
+}
@@ -0,0 +1,30 @@
+use {
+    crate::state::FeeTransfer,
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct FeesTransferred {
+    pub config: Pubkey,
+    pub payer: Pubkey,
+    pub target_program: Pubkey,
+    pub fee_instruction_index: u8,
+    pub base_amount: u64,
+    pub total_fee_amount: u64, // after discount
+    pub discount_percent: u64, // from the payer's FeeWaiver, 0 if none
+    pub fee_mint: Pubkey, // Pubkey::default() for SOL
+    pub transfers: Vec<FeeTransfer>, // per wallet, remainder treasury last
+    pub is_waived: bool, // config inactive with the Waive policy, nothing transferred
+    pub is_accrued: bool, // transfers were credited to the FeeVault instead of paid out
+    pub transferred_at: u64,
+}
+
+#[event]
+pub struct FeesClaimed {
+    pub config: Pubkey,
+    pub fee_vault: Pubkey,
+    pub wallet: Pubkey,
+    pub fee_mint: Pubkey, // Pubkey::default() for SOL
+    pub amount: u64,
+    pub claimed_at: u64,
+}
@@ -0,0 +1,5 @@
+pub mod config;
+pub use config::*;
+
+pub mod fee;
+pub use fee::*;
@@ -0,0 +1,118 @@
+use {
+    crate::{
+        constant::*,
+        cpi::{accounts::TransferFeesCtx, transfer_fees},
+        error::ContractError,
+        processor::TransferFeesIx,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub struct FeeTokenAccounts<'info> { // token fee configs only
+    pub fee_mint: AccountInfo<'info>,
+    pub payer_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+// Calls transfer_fees from program_id(the caller's ctx.program_id), signing with its fee caller PDA.
+// remaining_accounts are the fee wallets and the payer's FeeWaiver, as for transfer_fees.
+pub fn charge_fees<'info>(
+    program_id: &Pubkey,
+    fee_governance_hub: AccountInfo<'info>,
+    accounts: TransferFeesCtx<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    ix: TransferFeesIx,
+) -> Result<()> {
+    require!(fee_governance_hub.key() == crate::ID, ContractError::InvalidInstruction);
+    require!(accounts.target_program.key() == *program_id, ContractError::InvalidConfig);
+
+    let (fee_caller, fee_caller_bump) = Pubkey::find_program_address(&[FEE_CALLER_TAG], program_id);
+    require!(accounts.fee_caller.key() == fee_caller, ContractError::InvalidAuthority);
+
+    transfer_fees(
+        CpiContext::new_with_signer(fee_governance_hub, accounts, &[&[FEE_CALLER_TAG, &[fee_caller_bump]]])
+            .with_remaining_accounts(remaining_accounts.to_vec()),
+        ix,
+    )
+}
+
+// Defines FeeAccounts, the accounts an integrating program needs to charge fees, in the calling module.
+// Invoke it once in a module that has `use anchor_lang::prelude::*;`, glob import that module where it is
+// embedded(`pub fee_accounts: FeeAccounts<'info>`), then call `ctx.accounts.fee_accounts.charge(..)`.
+// Anchor only resolves nested Accounts structs of the same crate, so this can't be a plain struct of the hub.
+#[macro_export]
+macro_rules! fee_accounts {
+    () => {
+        #[derive(Accounts)]
+        pub struct FeeAccounts<'info> {
+            #[account(
+                constraint = fee_config.program == this_program.key() @ $crate::error::ContractError::InvalidConfig,
+            )]
+            pub fee_config: Box<Account<'info, $crate::state::Config>>,
+
+            /// CHECK: checked by fee_governance_hub, only passed when fee_config is the program's default config
+            pub fee_instruction_config: Option<UncheckedAccount<'info>>,
+
+            pub fee_global_config: Option<Box<Account<'info, $crate::state::GlobalConfig>>>,
+
+            /// CHECK: checked by fee_governance_hub, accrual mode fee configs only
+            #[account(mut)]
+            pub fee_vault: Option<UncheckedAccount<'info>>,
+
+            /// CHECK: checked by fee_governance_hub, accrual mode token fee configs only
+            #[account(mut)]
+            pub fee_vault_token_account: Option<UncheckedAccount<'info>>,
+
+            /// CHECK: checked by fee_governance_hub
+            #[account(mut)]
+            pub fee_stats: Option<UncheckedAccount<'info>>,
+
+            /// CHECK: signs the fee CPI on behalf of this_program, checked in charge
+            pub fee_caller: UncheckedAccount<'info>,
+
+            /// CHECK: checked against the calling program id in charge
+            pub this_program: UncheckedAccount<'info>,
+
+            pub fee_governance_hub: Program<'info, $crate::program::FeeGovernanceHub>,
+        }
+
+        impl<'info> FeeAccounts<'info> {
+            pub fn charge(
+                &self,
+                program_id: &Pubkey,
+                payer: AccountInfo<'info>,
+                token_accounts: Option<$crate::integration::FeeTokenAccounts<'info>>,
+                system_program: AccountInfo<'info>,
+                remaining_accounts: &[AccountInfo<'info>],
+                ix: $crate::processor::TransferFeesIx,
+            ) -> Result<()> {
+                let (fee_mint, payer_token_account, token_program) = match token_accounts {
+                    Some(token_accounts) => (Some(token_accounts.fee_mint), Some(token_accounts.payer_token_account), Some(token_accounts.token_program)),
+                    None => (None, None, None),
+                };
+
+                $crate::integration::charge_fees(
+                    program_id,
+                    self.fee_governance_hub.to_account_info(),
+                    $crate::cpi::accounts::TransferFeesCtx {
+                        payer,
+                        config: self.fee_config.to_account_info(),
+                        fee_instruction_config: self.fee_instruction_config.as_ref().map(|account| account.to_account_info()),
+                        global_config: self.fee_global_config.as_ref().map(|account| account.to_account_info()),
+                        target_program: self.this_program.to_account_info(),
+                        fee_caller: self.fee_caller.to_account_info(),
+                        fee_mint,
+                        payer_token_account,
+                        token_program,
+                        fee_vault: self.fee_vault.as_ref().map(|account| account.to_account_info()),
+                        fee_vault_token_account: self.fee_vault_token_account.as_ref().map(|account| account.to_account_info()),
+                        fee_stats: self.fee_stats.as_ref().map(|account| account.to_account_info()),
+                        system_program,
+                    },
+                    remaining_accounts,
+                    ix,
+                )
+            }
+        }
+    };
+}
@@ -2,10 +2,18 @@ use anchor_lang::prelude::*;
 
 declare_id!("F6kQqFTchcczKgSJJLvVqB6jHrq67gchMW7Af7hjHycQ");
 
+/// client, off-chain only
+#[cfg(not(target_os = "solana"))]
+pub mod client;
 /// constant
 pub mod constant;
 /// error
 pub mod error;
+/// event
+pub mod event;
+/// fee accounts and CPI helper for integrating programs
+#[cfg(feature = "cpi")]
+pub mod integration;
 /// processor
 pub mod processor;
 /// states
@@ -13,23 +21,182 @@ pub mod state;
 /// utils
 pub mod utils;
 
-use crate::processor::*;
+use crate::{processor::*, state::FeeQuote};
 
 #[program]
 pub mod fee_governance_hub {
     use super::*;
 
+    // admin registry
+    pub fn init_admin_registry(ctx: Context<InitAdminRegistryCtx>) -> Result<()> {
+        process_init_admin_registry::handler(ctx)
+    }
+
+    pub fn add_admin(ctx: Context<AddAdminCtx>, ix: AddAdminIx) -> Result<()> {
+        process_add_admin::handler(ctx, ix)
+    }
+
+    pub fn remove_admin(ctx: Context<RemoveAdminCtx>, ix: RemoveAdminIx) -> Result<()> {
+        process_remove_admin::handler(ctx, ix)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdminCtx>, ix: ProposeAdminIx) -> Result<()> {
+        process_propose_admin::handler(ctx, ix)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdminCtx>) -> Result<()> {
+        process_accept_admin::handler(ctx)
+    }
+
+    pub fn set_approval_threshold(ctx: Context<SetApprovalThresholdCtx>, ix: SetApprovalThresholdIx) -> Result<()> {
+        process_set_approval_threshold::handler(ctx, ix)
+    }
+
+    pub fn set_min_update_delay(ctx: Context<SetMinUpdateDelayCtx>, ix: SetMinUpdateDelayIx) -> Result<()> {
+        process_set_min_update_delay::handler(ctx, ix)
+    }
+
+    // multisig
+    pub fn create_proposal(ctx: Context<CreateProposalCtx>, ix: CreateProposalIx) -> Result<()> {
+        process_create_proposal::handler(ctx, ix)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposalCtx>) -> Result<()> {
+        process_approve_proposal::handler(ctx)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposalCtx>) -> Result<()> {
+        process_execute_proposal::handler(ctx)
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposalCtx>) -> Result<()> {
+        process_cancel_proposal::handler(ctx)
+    }
+
+    pub fn create_admin_proposal(ctx: Context<CreateAdminProposalCtx>, ix: CreateAdminProposalIx) -> Result<()> {
+        process_create_admin_proposal::handler(ctx, ix)
+    }
+
+    pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposalCtx>) -> Result<()> {
+        process_approve_admin_proposal::handler(ctx)
+    }
+
+    pub fn execute_admin_proposal(ctx: Context<ExecuteAdminProposalCtx>) -> Result<()> {
+        process_execute_admin_proposal::handler(ctx)
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposalCtx>) -> Result<()> {
+        process_cancel_admin_proposal::handler(ctx)
+    }
+
     // admin
     pub fn create_config(ctx: Context<CreateConfigCtx>, ix: CreateConfigIx) -> Result<()> {
         process_create_config::handler(ctx, ix)
     }
 
+    pub fn create_default_config(ctx: Context<CreateDefaultConfigCtx>, ix: CreateConfigIx) -> Result<()> {
+        process_create_default_config::handler(ctx, ix)
+    }
+
     pub fn update_config(ctx: Context<UpdateConfigCtx>, ix: UpdateConfigIx) -> Result<()> {
         process_update_config::handler(ctx, ix)
     }
+
+    pub fn create_configs_batch<'info>(ctx: Context<'_, '_, '_, 'info, CreateConfigsBatchCtx<'info>>, ix: CreateConfigsBatchIx) -> Result<()> {
+        process_create_configs_batch::handler(ctx, ix)
+    }
+
+    pub fn update_configs_batch<'info>(ctx: Context<'_, '_, 'info, 'info, UpdateConfigsBatchCtx<'info>>, ix: UpdateConfigsBatchIx) -> Result<()> {
+        process_update_configs_batch::handler(ctx, ix)
+    }
+
+    pub fn set_config_active(ctx: Context<SetConfigActiveCtx>, ix: SetConfigActiveIx) -> Result<()> {
+        process_set_config_active::handler(ctx, ix)
+    }
+
+    pub fn close_config(ctx: Context<CloseConfigCtx>, ix: CloseConfigIx) -> Result<()> {
+        process_close_config::handler(ctx, ix)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfigCtx>, ix: MigrateConfigIx) -> Result<()> {
+        process_migrate_config::handler(ctx, ix)
+    }
+
+    pub fn schedule_config_update(ctx: Context<ScheduleConfigUpdateCtx>, ix: ScheduleConfigUpdateIx) -> Result<()> {
+        process_schedule_config_update::handler(ctx, ix)
+    }
+
+    pub fn apply_pending_update(ctx: Context<ApplyPendingUpdateCtx>) -> Result<()> {
+        process_apply_pending_update::handler(ctx)
+    }
+
+    pub fn cancel_pending_update(ctx: Context<CancelPendingUpdateCtx>) -> Result<()> {
+        process_cancel_pending_update::handler(ctx)
+    }
+
+    pub fn create_global_config(ctx: Context<CreateGlobalConfigCtx>, ix: CreateGlobalConfigIx) -> Result<()> {
+        process_create_global_config::handler(ctx, ix)
+    }
+
+    pub fn update_global_config(ctx: Context<UpdateGlobalConfigCtx>, ix: UpdateGlobalConfigIx) -> Result<()> {
+        process_update_global_config::handler(ctx, ix)
+    }
     
+    // program managers
+    pub fn add_program_manager(ctx: Context<AddProgramManagerCtx>) -> Result<()> {
+        process_add_program_manager::handler(ctx)
+    }
+
+    pub fn remove_program_manager(ctx: Context<RemoveProgramManagerCtx>) -> Result<()> {
+        process_remove_program_manager::handler(ctx)
+    }
+
+    pub fn manager_update_config(ctx: Context<ManagerUpdateConfigCtx>, ix: UpdateConfigIx) -> Result<()> {
+        process_manager_update_config::handler(ctx, ix)
+    }
+
+    // fee schedules
+    pub fn add_fee_schedule(ctx: Context<AddFeeScheduleCtx>, ix: AddFeeScheduleIx) -> Result<()> {
+        process_add_fee_schedule::handler(ctx, ix)
+    }
+
+    pub fn remove_fee_schedule(ctx: Context<RemoveFeeScheduleCtx>, ix: RemoveFeeScheduleIx) -> Result<()> {
+        process_remove_fee_schedule::handler(ctx, ix)
+    }
+
+    // fee waivers
+    pub fn create_fee_waiver(ctx: Context<CreateFeeWaiverCtx>, ix: CreateFeeWaiverIx) -> Result<()> {
+        process_create_fee_waiver::handler(ctx, ix)
+    }
+
+    pub fn update_fee_waiver(ctx: Context<UpdateFeeWaiverCtx>, ix: UpdateFeeWaiverIx) -> Result<()> {
+        process_update_fee_waiver::handler(ctx, ix)
+    }
+
+    pub fn remove_fee_waiver(ctx: Context<RemoveFeeWaiverCtx>) -> Result<()> {
+        process_remove_fee_waiver::handler(ctx)
+    }
+
+    // fee vaults
+    pub fn init_fee_vault(ctx: Context<InitFeeVaultCtx>) -> Result<()> {
+        process_init_fee_vault::handler(ctx)
+    }
+
+    pub fn claim_fees(ctx: Context<ClaimFeesCtx>) -> Result<()> {
+        process_claim_fees::handler(ctx)
+    }
+
+    // fee stats
+    pub fn init_fee_stats(ctx: Context<InitFeeStatsCtx>) -> Result<()> {
+        process_init_fee_stats::handler(ctx)
+    }
+
     // cpi
     pub fn transfer_fees<'info>(ctx: Context<'_, '_, '_, 'info, TransferFeesCtx<'info>>, ix: TransferFeesIx) -> Result<()> {
         process_transfer_fees::handler(ctx, ix)
     }
+
+    pub fn quote_fees(ctx: Context<QuoteFeesCtx>, ix: QuoteFeesIx) -> Result<FeeQuote> {
+        process_quote_fees::handler(ctx, ix)
+    }
 }
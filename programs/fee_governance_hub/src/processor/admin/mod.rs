@@ -1,6 +1,112 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod process_create_config;
-#[allow(ambiguous_glob_reexports)]
 pub use process_create_config::*;
 
+pub mod process_create_default_config;
+pub use process_create_default_config::*;
+
 pub mod process_update_config;
 pub use process_update_config::*;
+
+pub mod process_create_global_config;
+pub use process_create_global_config::*;
+
+pub mod process_update_global_config;
+pub use process_update_global_config::*;
+
+pub mod process_init_admin_registry;
+pub use process_init_admin_registry::*;
+
+pub mod process_add_admin;
+pub use process_add_admin::*;
+
+pub mod process_remove_admin;
+pub use process_remove_admin::*;
+
+pub mod process_propose_admin;
+pub use process_propose_admin::*;
+
+pub mod process_accept_admin;
+pub use process_accept_admin::*;
+
+pub mod process_set_approval_threshold;
+pub use process_set_approval_threshold::*;
+
+pub mod process_create_proposal;
+pub use process_create_proposal::*;
+
+pub mod process_approve_proposal;
+pub use process_approve_proposal::*;
+
+pub mod process_execute_proposal;
+pub use process_execute_proposal::*;
+
+pub mod process_set_min_update_delay;
+pub use process_set_min_update_delay::*;
+
+pub mod process_schedule_config_update;
+pub use process_schedule_config_update::*;
+
+pub mod process_apply_pending_update;
+pub use process_apply_pending_update::*;
+
+pub mod process_cancel_pending_update;
+pub use process_cancel_pending_update::*;
+
+pub mod process_set_config_active;
+pub use process_set_config_active::*;
+
+pub mod process_close_config;
+pub use process_close_config::*;
+
+pub mod process_create_fee_waiver;
+pub use process_create_fee_waiver::*;
+
+pub mod process_update_fee_waiver;
+pub use process_update_fee_waiver::*;
+
+pub mod process_remove_fee_waiver;
+pub use process_remove_fee_waiver::*;
+
+pub mod process_init_fee_vault;
+pub use process_init_fee_vault::*;
+
+pub mod process_init_fee_stats;
+pub use process_init_fee_stats::*;
+
+pub mod process_add_fee_schedule;
+pub use process_add_fee_schedule::*;
+
+pub mod process_remove_fee_schedule;
+pub use process_remove_fee_schedule::*;
+
+pub mod process_add_program_manager;
+pub use process_add_program_manager::*;
+
+pub mod process_remove_program_manager;
+pub use process_remove_program_manager::*;
+
+pub mod process_migrate_config;
+pub use process_migrate_config::*;
+
+pub mod process_create_configs_batch;
+pub use process_create_configs_batch::*;
+
+pub mod process_update_configs_batch;
+pub use process_update_configs_batch::*;
+
+pub mod process_cancel_proposal;
+pub use process_cancel_proposal::*;
+
+pub mod process_create_admin_proposal;
+pub use process_create_admin_proposal::*;
+
+pub mod process_approve_admin_proposal;
+pub use process_approve_admin_proposal::*;
+
+pub mod process_execute_admin_proposal;
+pub use process_execute_admin_proposal::*;
+
+pub mod process_cancel_admin_proposal;
+pub use process_cancel_admin_proposal::*;
@@ -0,0 +1,25 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct AcceptAdminCtx<'info> {
+    #[account(
+      constraint = admin_registry.has_pending_admin() && admin_registry.pending_admin == authority.key() @ ContractError::InvalidPendingAdmin
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+}
+
+// Step 2 of the handover: the pending admin takes over the proposer's seat.
+pub fn handler(ctx: Context<AcceptAdminCtx>) -> Result<()> {
+    ctx.accounts.admin_registry.accept_admin()
+}
@@ -0,0 +1,33 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddAdminIx {
+    admin: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: AddAdminIx)]
+pub struct AddAdminCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+}
+
+pub fn handler(ctx: Context<AddAdminCtx>, ix: AddAdminIx) -> Result<()> {
+    let admin_registry = &mut ctx.accounts.admin_registry;
+
+    require!(!admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+
+    admin_registry.add_admin(ix.admin)
+}
@@ -0,0 +1,66 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddFeeScheduleIx {
+    fee_instruction_index: u64,
+    fee_schedule: FeeSchedule,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: AddFeeScheduleIx)]
+pub struct AddFeeScheduleCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+        realloc = Config::space(config.fee_wallets.len()), // configs created before fee schedules have no room for them
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddFeeScheduleCtx>, ix: AddFeeScheduleIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let config = &mut ctx.accounts.config;
+    let old_values = ConfigValues::from(&***config);
+
+    config.add_fee_schedule(ix.fee_schedule, current_time)?;
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        old_values,
+        new_values: ConfigValues::from(&***config),
+        updated_at: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct AddProgramManagerCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = std::mem::size_of::<ProgramManager>() + 8,
+        seeds = [PROGRAM_MANAGER_TAG, target_program.key().as_ref(), manager.key().as_ref()],
+        bump,
+    )]
+    pub program_manager: Box<Account<'info, ProgramManager>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    /// CHECK: We read this key only
+    pub manager: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddProgramManagerCtx>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+
+    let program_manager = &mut ctx.accounts.program_manager;
+
+    program_manager.bump = ctx.bumps.program_manager;
+    program_manager.program = ctx.accounts.target_program.key();
+    program_manager.manager = ctx.accounts.manager.key();
+    program_manager.granted_by = ctx.accounts.authority.key();
+
+    program_manager.created_at = Clock::get()?.unix_timestamp as u64;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use {
+    crate::{constant::*, error::ContractError, processor::{execute_config_action, validate_manager_action, ConfigActionAccounts}, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ApplyPendingUpdateCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // anyone can apply once effective_at has passed
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+        address = pending_update.config,
+        realloc = pending_update.action.config_space(&config),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_CONFIG_UPDATE_TAG, pending_update.config.as_ref()],
+        bump = pending_update.bump,
+        close = scheduled_by,
+    )]
+    pub pending_update: Box<Account<'info, PendingConfigUpdate>>,
+
+    #[account(
+        mut,
+        address = pending_update.scheduled_by,
+    )]
+    /// CHECK: rent receiver only
+    pub scheduled_by: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PROGRAM_MANAGER_TAG, config.program.as_ref(), pending_update.scheduled_by.as_ref()],
+        bump = program_manager.bump,
+    )]
+    pub program_manager: Option<Box<Account<'info, ProgramManager>>>, // updates scheduled by a program manager only
+
+    /// CHECK: PDA of the fee waiver action's payer, checked by execute_config_action
+    #[account(mut)]
+    pub fee_waiver: Option<UncheckedAccount<'info>>, // fee waiver actions only
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ApplyPendingUpdateCtx>) -> Result<()> {
+    let pending_update = &ctx.accounts.pending_update;
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(current_time >= pending_update.effective_at, ContractError::TimelockNotElapsed);
+
+    // the manager may have been revoked, or admins may have changed the config since
+    if !ctx.accounts.admin_registry.is_admin(&pending_update.scheduled_by) {
+        require!(ctx.accounts.program_manager.is_some(), ContractError::InvalidAuthority);
+        validate_manager_action(&pending_update.action, &ctx.accounts.config)?;
+    }
+
+    execute_config_action(&pending_update.action, &mut ctx.accounts.config, ConfigActionAccounts {
+        authority: &ctx.accounts.authority.to_account_info(),
+        fee_waiver: ctx.accounts.fee_waiver.as_ref(),
+        rent_receiver: &ctx.accounts.scheduled_by.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    }, current_time)
+}
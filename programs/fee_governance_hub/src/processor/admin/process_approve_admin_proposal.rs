@@ -0,0 +1,42 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ApproveAdminProposalCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_TAG, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<ApproveAdminProposalCtx>) -> Result<()> {
+    let admin_registry = &ctx.accounts.admin_registry;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(!proposal.has_approved(ctx.accounts.authority.key), ContractError::AlreadyApproved);
+    require!(
+        !admin_registry.is_proposal_expired(proposal.created_at, Clock::get()?.unix_timestamp as u64),
+        ContractError::ProposalExpired
+    );
+
+    proposal.approvals.retain(|approval| admin_registry.is_admin(approval)); // drop removed admins to keep room
+    proposal.approvals.push(ctx.accounts.authority.key());
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ApproveProposalCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_PROPOSAL_TAG, proposal.config.as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, ConfigProposal>>,
+}
+
+pub fn handler(ctx: Context<ApproveProposalCtx>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(!proposal.has_approved(ctx.accounts.authority.key), ContractError::AlreadyApproved);
+    require!(
+        !ctx.accounts.admin_registry.is_proposal_expired(proposal.created_at, Clock::get()?.unix_timestamp as u64),
+        ContractError::ProposalExpired
+    );
+
+    proposal.approvals.retain(|approval| ctx.accounts.admin_registry.is_admin(approval)); // drop removed admins to keep room
+    proposal.approvals.push(ctx.accounts.authority.key());
+
+    Ok(())
+}
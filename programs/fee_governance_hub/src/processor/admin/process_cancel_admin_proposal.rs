@@ -0,0 +1,46 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelAdminProposalCtx<'info> {
+    pub authority: Signer<'info>, // the proposer, or any admin once the proposal expired
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_TAG, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        close = proposer,
+    )]
+    pub proposal: Box<Account<'info, AdminProposal>>,
+
+    #[account(
+        mut,
+        address = proposal.proposer,
+    )]
+    /// CHECK: rent receiver only
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelAdminProposalCtx>) -> Result<()> {
+    let admin_registry = &ctx.accounts.admin_registry;
+    let authority = ctx.accounts.authority.key();
+
+    require!(
+        authority == ctx.accounts.proposal.proposer || (
+            admin_registry.is_admin(&authority)
+            && admin_registry.is_proposal_expired(ctx.accounts.proposal.created_at, Clock::get()?.unix_timestamp as u64)
+        ),
+        ContractError::InvalidAuthority
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct CancelPendingUpdateCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) || pending_update.scheduled_by == authority.key() @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>, // an admin or the scheduler
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_CONFIG_UPDATE_TAG, pending_update.config.as_ref()],
+        bump = pending_update.bump,
+        close = scheduled_by,
+    )]
+    pub pending_update: Box<Account<'info, PendingConfigUpdate>>,
+
+    #[account(
+        mut,
+        address = pending_update.scheduled_by,
+    )]
+    /// CHECK: rent receiver only
+    pub scheduled_by: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelPendingUpdateCtx>) -> Result<()> {
+    let pending_update = &ctx.accounts.pending_update;
+
+    emit!(ConfigUpdateCancelled {
+        config: pending_update.config,
+        authority: ctx.accounts.authority.key(),
+        effective_at: pending_update.effective_at,
+        cancelled_at: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
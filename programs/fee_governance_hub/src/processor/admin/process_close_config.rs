@@ -0,0 +1,64 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CloseConfigIx {
+    fee_instruction_index: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CloseConfigIx)]
+pub struct CloseConfigCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    /// CHECK: Closed in any layout, it is read with Config::try_deserialize_versioned
+    #[account(
+        mut,
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(rent_receiver.key) @ ContractError::InvalidAuthority
+    )]
+    /// CHECK: rent receiver only, must be an admin
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseConfigCtx>, _: CloseConfigIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    let config_info = ctx.accounts.config.to_account_info();
+    let config = Config::try_deserialize_versioned(&config_info.try_borrow_data()?)?; // v1 configs can be closed without migrating
+
+    close_account(&config_info, &ctx.accounts.rent_receiver.to_account_info())?;
+
+    emit!(ConfigClosed {
+        config: config_info.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        rent_receiver: ctx.accounts.rent_receiver.key(),
+        closed_at: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateAdminProposalIx {
+    proposal_id: u64,
+    action: AdminAction,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateAdminProposalIx)]
+pub struct CreateAdminProposalCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AdminProposal::space(),
+        seeds = [ADMIN_PROPOSAL_TAG, &ix.proposal_id.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: Box<Account<'info, AdminProposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateAdminProposalCtx>, ix: CreateAdminProposalIx) -> Result<()> {
+    ix.action.apply(&mut (**ctx.accounts.admin_registry).clone())?; // reject invalid actions up front
+
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.bump = ctx.bumps.proposal;
+    proposal.proposal_id = ix.proposal_id;
+    proposal.proposer = ctx.accounts.authority.key();
+    proposal.action = ix.action;
+    proposal.approvals = vec![ctx.accounts.authority.key()]; // proposer approves by creating
+
+    proposal.created_at = Clock::get()?.unix_timestamp as u64;
+
+    Ok(())
+}
@@ -1,15 +1,24 @@
 use {
-    crate::{constant::*, error::ContractError, state::*, utils::*},
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default)]
 pub struct CreateConfigIx {
-    fee_instruction_index: u64,
-    is_using_global_fee_wallets: bool,
-    fee_wallets: [FeeWallet; MAX_FEE_WALLETS_LEN],
-    fee_amount: u64,
-    fee_instruction_name: String,
+    pub fee_instruction_index: u64,
+    pub is_using_global_fee_wallets: bool,
+    pub fee_wallets: Vec<FeeWallet>,
+    pub fee_amount: u64,
+    pub fee_instruction_name: String,
+    pub fee_mode: FeeMode,
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+    pub fee_mint: Pubkey,
+    pub remainder_policy: RemainderPolicy,
+    pub remainder_treasury: Pubkey,
+    pub is_accrual_mode: bool,
+    pub platform_share_percent: u64,
 }
 
 #[derive(Accounts)]
@@ -17,37 +26,140 @@ pub struct CreateConfigIx {
 pub struct CreateConfigCtx<'info> {
     #[account(
       mut,
-      constraint = is_admin(authority.key) @ ContractError::InvalidAuthority
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
     #[account(
         init,
         payer = authority,
-        space = std::mem::size_of::<Config>() + 8 + MAX_FEE_WALLETS_LEN * std::mem::size_of::<FeeWallet>() + MAX_FEE_INSTRUCTION_NAME_LEN,
+        space = ix.space(),
         seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
         bump,
     )]
     pub config: Box<Account<'info, Config>>,
 
+    /// CHECK: The program's default config, its PDA is only checked for data
+    #[account(
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &DEFAULT_FEE_INSTRUCTION_INDEX.to_le_bytes()],
+        bump,
+    )]
+    pub default_config: UncheckedAccount<'info>,
+
     /// CHECK: We read this key only
     pub target_program: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+impl CreateConfigIx {
+    // config account size for this payload
+    pub fn space(&self) -> usize {
+        Config::space(compact_fee_wallets(&self.fee_wallets).len())
+    }
+
+    // DEFAULT_FEE_INSTRUCTION_INDEX is reserved for the program's default config, created by create_default_config only
+    pub fn validate_fee_instruction_index(&self, is_default_config: bool) -> Result<()> {
+        require!(self.fee_instruction_index <= DEFAULT_FEE_INSTRUCTION_INDEX, ContractError::InvalidFeeInstructionIndex);
+        require!(
+            (self.fee_instruction_index == DEFAULT_FEE_INSTRUCTION_INDEX) == is_default_config,
+            ContractError::InvalidFeeInstructionIndex
+        );
+
+        Ok(())
+    }
+
+    pub fn init(&self, config: &mut Config, bump: u8, program: Pubkey, created_at: u64) -> Result<()> {
+        config.bump = bump;
+        config.program = program;
+        config.fee_instruction_index = u8::try_from(self.fee_instruction_index).map_err(|_| ContractError::InvalidFeeInstructionIndex)?;
+        config.is_using_global_fee_wallets = self.is_using_global_fee_wallets;
+        config.fee_wallets = compact_fee_wallets(&self.fee_wallets);
+        config.fee_amount = self.fee_amount;
+        config.fee_instruction_name.clone_from(&self.fee_instruction_name);
+        config.fee_mode = self.fee_mode;
+        config.fee_bps = self.fee_bps;
+        config.min_fee_amount = self.min_fee_amount;
+        config.max_fee_amount = self.max_fee_amount;
+        config.fee_mint = self.fee_mint;
+        config.remainder_policy = self.remainder_policy;
+        config.remainder_treasury = self.remainder_treasury;
+        config.is_accrual_mode = self.is_accrual_mode;
+        config.platform_share_percent = self.platform_share_percent;
+        config.version = CONFIG_VERSION;
+
+        config.validate()?;
+
+        config.created_at = created_at;
+
+        Ok(())
+    }
+}
+
 pub fn handler(ctx: Context<CreateConfigCtx>, ix: CreateConfigIx) -> Result<()> {
+    ix.validate_fee_instruction_index(false)?;
+    // an instruction config takes over from the default config right away, so it waits out the timelock as a CreateConfig admin proposal
+    require!(
+        !ctx.accounts.admin_registry.is_timelock_required() || ctx.accounts.default_config.data_is_empty(),
+        ContractError::TimelockRequired
+    );
+
     let config = &mut ctx.accounts.config;
 
-    config.bump = ctx.bumps.config;
-    config.program = ctx.accounts.target_program.key();
-    config.fee_instruction_index = ix.fee_instruction_index as u8;
-    config.is_using_global_fee_wallets = ix.is_using_global_fee_wallets;
-    config.fee_wallets = ix.fee_wallets.to_vec();
-    config.fee_amount = ix.fee_amount;
-    config.fee_instruction_name = ix.fee_instruction_name;
-    
-    config.created_at = Clock::get()?.unix_timestamp as u64;
+    ix.init(config, ctx.bumps.config, ctx.accounts.target_program.key(), Clock::get()?.unix_timestamp as u64)?;
+
+    emit!(ConfigCreated {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        values: ConfigValues::from(&***config),
+        created_at: config.created_at,
+    });
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_config_ix(fee_instruction_index: u64) -> CreateConfigIx {
+        CreateConfigIx {
+            fee_instruction_index,
+            is_using_global_fee_wallets: true,
+            fee_wallets: Vec::new(),
+            fee_amount: 0,
+            fee_instruction_name: String::new(),
+            fee_mode: FeeMode::Flat,
+            fee_bps: 0,
+            min_fee_amount: 0,
+            max_fee_amount: 0,
+            fee_mint: Pubkey::default(),
+            remainder_policy: RemainderPolicy::default(),
+            remainder_treasury: Pubkey::default(),
+            is_accrual_mode: false,
+            platform_share_percent: 0,
+        }
+    }
+
+    #[test]
+    fn default_fee_instruction_index_is_reserved() {
+        create_config_ix(0).validate_fee_instruction_index(false).unwrap();
+        create_config_ix(DEFAULT_FEE_INSTRUCTION_INDEX - 1).validate_fee_instruction_index(false).unwrap();
+        create_config_ix(DEFAULT_FEE_INSTRUCTION_INDEX).validate_fee_instruction_index(true).unwrap();
+
+        let error = ContractError::InvalidFeeInstructionIndex.into();
+        assert_eq!(create_config_ix(DEFAULT_FEE_INSTRUCTION_INDEX).validate_fee_instruction_index(false).unwrap_err(), error);
+        assert_eq!(create_config_ix(0).validate_fee_instruction_index(true).unwrap_err(), error);
+        // 256 would be truncated to 0 in the config
+        assert_eq!(create_config_ix(DEFAULT_FEE_INSTRUCTION_INDEX + 1).validate_fee_instruction_index(false).unwrap_err(), error);
+        assert_eq!(create_config_ix(DEFAULT_FEE_INSTRUCTION_INDEX + 1).validate_fee_instruction_index(true).unwrap_err(), error);
+    }
+}
@@ -0,0 +1,85 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, processor::CreateConfigIx, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateConfigsBatchIx {
+    entries: Vec<CreateConfigIx>,
+}
+
+// remaining accounts: the config PDA of each entry, writable, in entry order
+#[derive(Accounts)]
+pub struct CreateConfigsBatchCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    /// CHECK: The program's default config, its PDA is only checked for data
+    #[account(
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &DEFAULT_FEE_INSTRUCTION_INDEX.to_le_bytes()],
+        bump,
+    )]
+    pub default_config: UncheckedAccount<'info>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CreateConfigsBatchCtx<'info>>, ix: CreateConfigsBatchIx) -> Result<()> {
+    require!(!ix.entries.is_empty() && ix.entries.len() <= MAX_CONFIG_BATCH_LEN, ContractError::InvalidConfigBatch);
+    require!(ctx.remaining_accounts.len() == ix.entries.len(), ContractError::InvalidRemainingAccounts);
+    // an instruction config takes over from the default config right away, so it waits out the timelock as a CreateConfig admin proposal
+    require!(
+        !ctx.accounts.admin_registry.is_timelock_required() || ctx.accounts.default_config.data_is_empty(),
+        ContractError::TimelockRequired
+    );
+
+    let target_program = ctx.accounts.target_program.key();
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    for (entry, config_info) in ix.entries.iter().zip(ctx.remaining_accounts) {
+        entry.validate_fee_instruction_index(false)?;
+
+        let fee_instruction_index = entry.fee_instruction_index.to_le_bytes();
+        let (address, bump) = Pubkey::find_program_address(&[CONFIG_TAG, target_program.as_ref(), &fee_instruction_index], &crate::ID);
+        require_keys_eq!(config_info.key(), address, ContractError::InvalidRemainingAccounts);
+        // already created, or repeated in the batch
+        require!(config_info.owner == &System::id() && config_info.data_is_empty(), ContractError::InvalidConfigBatch);
+
+        create_pda_account(
+            &authority,
+            config_info,
+            &system_program,
+            entry.space(),
+            &[CONFIG_TAG, target_program.as_ref(), &fee_instruction_index, &[bump]],
+        )?;
+
+        let mut config = Config::default();
+        entry.init(&mut config, bump, target_program, current_time)?;
+        config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+        emit!(ConfigCreated {
+            config: address,
+            authority: authority.key(),
+            program: config.program,
+            fee_instruction_index: config.fee_instruction_index,
+            values: ConfigValues::from(&config),
+            created_at: config.created_at,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, processor::CreateConfigIx, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(ix: CreateConfigIx)]
+pub struct CreateDefaultConfigCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ix.space(),
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &DEFAULT_FEE_INSTRUCTION_INDEX.to_le_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Creates the program's default config, ix.fee_instruction_index must be DEFAULT_FEE_INSTRUCTION_INDEX.
+pub fn handler(ctx: Context<CreateDefaultConfigCtx>, ix: CreateConfigIx) -> Result<()> {
+    ix.validate_fee_instruction_index(true)?;
+
+    let config = &mut ctx.accounts.config;
+
+    ix.init(config, ctx.bumps.config, ctx.accounts.target_program.key(), Clock::get()?.unix_timestamp as u64)?;
+
+    emit!(ConfigCreated {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        values: ConfigValues::from(&***config),
+        created_at: config.created_at,
+    });
+
+    Ok(())
+}
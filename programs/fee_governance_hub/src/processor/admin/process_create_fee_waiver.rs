@@ -0,0 +1,61 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateFeeWaiverIx {
+    discount_percent: u64,
+    expires_at: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateFeeWaiverIx)]
+pub struct CreateFeeWaiverCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = std::mem::size_of::<FeeWaiver>() + 8,
+        seeds = [FEE_WAIVER_TAG, config.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub fee_waiver: Box<Account<'info, FeeWaiver>>,
+
+    /// CHECK: We read this key only
+    pub payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateFeeWaiverCtx>, ix: CreateFeeWaiverIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    let fee_waiver = &mut ctx.accounts.fee_waiver;
+
+    fee_waiver.bump = ctx.bumps.fee_waiver;
+    fee_waiver.config = ctx.accounts.config.key();
+    fee_waiver.payer = ctx.accounts.payer.key();
+    fee_waiver.set_discount(ix.discount_percent, ix.expires_at)?;
+
+    fee_waiver.created_at = Clock::get()?.unix_timestamp as u64;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateGlobalConfigIx {
+    fee_wallets: Vec<FeeWallet>,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateGlobalConfigIx)]
+pub struct CreateGlobalConfigCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalConfig::space(compact_fee_wallets(&ix.fee_wallets).len()),
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateGlobalConfigCtx>, ix: CreateGlobalConfigIx) -> Result<()> {
+    validate_fee_wallets(&ix.fee_wallets)?;
+
+    let global_config = &mut ctx.accounts.global_config;
+
+    global_config.bump = ctx.bumps.global_config;
+    global_config.fee_wallets = compact_fee_wallets(&ix.fee_wallets);
+
+    global_config.created_at = Clock::get()?.unix_timestamp as u64;
+    global_config.updated_at = global_config.created_at;
+
+    emit!(GlobalConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        old_fee_wallets: Vec::new(),
+        new_fee_wallets: global_config.fee_wallets.clone(),
+        updated_at: global_config.updated_at,
+    });
+
+    Ok(())
+}
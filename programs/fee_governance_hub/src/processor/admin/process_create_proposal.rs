@@ -0,0 +1,70 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateProposalIx {
+    pub proposal_id: u64,
+    pub fee_instruction_index: u64, // of the config
+    pub action: ConfigAction,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: CreateProposalIx)]
+pub struct CreateProposalCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigProposal::space(&ix.action),
+        seeds = [CONFIG_PROPOSAL_TAG, config.key().as_ref(), &ix.proposal_id.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: Box<Account<'info, ConfigProposal>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateProposalCtx>, ix: CreateProposalIx) -> Result<()> {
+    if let ConfigAction::Update(payload) = &ix.action {
+        require!(payload.fee_instruction_index == ix.fee_instruction_index, ContractError::InvalidConfig);
+    }
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    ix.action.apply(&mut (**ctx.accounts.config).clone(), current_time)?; // reject invalid actions up front
+
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.bump = ctx.bumps.proposal;
+    proposal.proposal_id = ix.proposal_id;
+    proposal.config = ctx.accounts.config.key();
+    proposal.proposer = ctx.accounts.authority.key();
+    proposal.action = ix.action;
+    proposal.approvals = vec![ctx.accounts.authority.key()]; // proposer approves by creating
+
+    proposal.created_at = current_time;
+
+    Ok(())
+}
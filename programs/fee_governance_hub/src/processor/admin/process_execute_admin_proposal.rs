@@ -0,0 +1,155 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ExecuteAdminProposalCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_PROPOSAL_TAG, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        close = proposer,
+    )]
+    pub proposal: Box<Account<'info, AdminProposal>>,
+
+    #[account(
+        mut,
+        address = proposal.proposer,
+    )]
+    /// CHECK: rent receiver only
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump = global_config.bump,
+    )]
+    pub global_config: Option<Box<Account<'info, GlobalConfig>>>, // UpdateGlobalConfig only
+
+    /// CHECK: PDA of the action's program and manager, checked by the handler
+    #[account(mut)]
+    pub program_manager: Option<UncheckedAccount<'info>>, // program manager actions only
+
+    /// CHECK: PDA of the CreateConfig action, created by the handler
+    #[account(mut)]
+    pub config: Option<UncheckedAccount<'info>>, // CreateConfig only
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteAdminProposalCtx>) -> Result<()> {
+    let admin_registry = &mut ctx.accounts.admin_registry;
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.num_valid_approvals(admin_registry) >= admin_registry.approval_threshold as usize,
+        ContractError::NotEnoughApprovals
+    );
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(admin_registry.is_proposal_executable(proposal.created_at, current_time)?, ContractError::TimelockNotElapsed);
+    require!(!admin_registry.is_proposal_expired(proposal.created_at, current_time), ContractError::ProposalExpired);
+
+    proposal.action.apply(admin_registry)?;
+
+    match &proposal.action {
+        AdminAction::UpdateGlobalConfig(fee_wallets) => {
+            let global_config = ctx.accounts.global_config.as_mut().ok_or(ContractError::GlobalConfigRequired)?;
+
+            resize_account(
+                &global_config.to_account_info(),
+                &ctx.accounts.authority.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                GlobalConfig::space(compact_fee_wallets(fee_wallets).len()),
+            )?;
+
+            let old_fee_wallets = global_config.update(fee_wallets, current_time)?;
+
+            emit!(GlobalConfigUpdated {
+                authority: ctx.accounts.authority.key(),
+                old_fee_wallets,
+                new_fee_wallets: global_config.fee_wallets.clone(),
+                updated_at: global_config.updated_at,
+            });
+        }
+        AdminAction::AddProgramManager { program, manager } => {
+            let (address, bump) = Pubkey::find_program_address(&[PROGRAM_MANAGER_TAG, program.as_ref(), manager.as_ref()], &crate::ID);
+            let program_manager_info = ctx.accounts.program_manager.as_ref()
+                .filter(|program_manager| program_manager.key() == address)
+                .ok_or(ContractError::ProgramManagerRequired)?;
+
+            create_pda_account(
+                &ctx.accounts.authority.to_account_info(),
+                program_manager_info,
+                &ctx.accounts.system_program.to_account_info(),
+                std::mem::size_of::<ProgramManager>() + 8,
+                &[PROGRAM_MANAGER_TAG, program.as_ref(), manager.as_ref(), &[bump]],
+            )?;
+
+            let program_manager = ProgramManager {
+                bump,
+                program: *program,
+                manager: *manager,
+                granted_by: proposal.proposer,
+                created_at: current_time,
+                ..Default::default()
+            };
+            program_manager.try_serialize(&mut &mut program_manager_info.try_borrow_mut_data()?[..])?;
+        }
+        AdminAction::RemoveProgramManager { program, manager } => {
+            let (address, _) = Pubkey::find_program_address(&[PROGRAM_MANAGER_TAG, program.as_ref(), manager.as_ref()], &crate::ID);
+            let program_manager_info = ctx.accounts.program_manager.as_ref()
+                .filter(|program_manager| program_manager.key() == address)
+                .ok_or(ContractError::ProgramManagerRequired)?;
+
+            ProgramManager::try_deserialize(&mut &program_manager_info.try_borrow_data()?[..])?; // must exist
+            close_account(program_manager_info, &ctx.accounts.authority.to_account_info())?;
+        }
+        AdminAction::CreateConfig { program, payload } => {
+            let fee_instruction_index = payload.fee_instruction_index.to_le_bytes();
+            let (address, bump) = Pubkey::find_program_address(&[CONFIG_TAG, program.as_ref(), &fee_instruction_index], &crate::ID);
+            let config_info = ctx.accounts.config.as_ref()
+                .filter(|config| config.key() == address)
+                .ok_or(ContractError::InvalidConfig)?;
+
+            create_pda_account(
+                &ctx.accounts.authority.to_account_info(),
+                config_info,
+                &ctx.accounts.system_program.to_account_info(),
+                payload.space(),
+                &[CONFIG_TAG, program.as_ref(), &fee_instruction_index, &[bump]],
+            )?;
+
+            let mut config = Config::default();
+            payload.init(&mut config, bump, *program, current_time)?;
+            config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+            emit!(ConfigCreated {
+                config: address,
+                authority: ctx.accounts.authority.key(),
+                program: config.program,
+                fee_instruction_index: config.fee_instruction_index,
+                values: ConfigValues::from(&config),
+                created_at: config.created_at,
+            });
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct ExecuteProposalCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+        address = proposal.config,
+        realloc = proposal.action.config_space(&config),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_PROPOSAL_TAG, proposal.config.as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        close = proposer,
+    )]
+    pub proposal: Box<Account<'info, ConfigProposal>>,
+
+    #[account(
+        mut,
+        address = proposal.proposer,
+    )]
+    /// CHECK: rent receiver only
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: PDA of the fee waiver action's payer, checked by execute_config_action
+    #[account(mut)]
+    pub fee_waiver: Option<UncheckedAccount<'info>>, // fee waiver actions only
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteProposalCtx>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.num_valid_approvals(&ctx.accounts.admin_registry) >= ctx.accounts.admin_registry.approval_threshold as usize,
+        ContractError::NotEnoughApprovals
+    );
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    require!(ctx.accounts.admin_registry.is_proposal_executable(proposal.created_at, current_time)?, ContractError::TimelockNotElapsed);
+    require!(
+        !ctx.accounts.admin_registry.is_proposal_expired(proposal.created_at, current_time),
+        ContractError::ProposalExpired
+    );
+
+    execute_config_action(&proposal.action, &mut ctx.accounts.config, ConfigActionAccounts {
+        authority: &ctx.accounts.authority.to_account_info(),
+        fee_waiver: ctx.accounts.fee_waiver.as_ref(),
+        rent_receiver: &ctx.accounts.authority.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    }, current_time)
+}
+
+pub struct ConfigActionAccounts<'a, 'info> {
+    pub authority: &'a AccountInfo<'info>, // pays for the accounts and the realloc of the action
+    pub fee_waiver: Option<&'a UncheckedAccount<'info>>,
+    pub rent_receiver: &'a AccountInfo<'info>, // of the accounts closed by the action
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+// Applies action to config and the accounts it touches, shared by execute_proposal and apply_pending_update.
+pub fn execute_config_action<'info>(
+    action: &ConfigAction,
+    config: &mut Account<'info, Config>,
+    accounts: ConfigActionAccounts<'_, 'info>,
+    current_time: u64,
+) -> Result<()> {
+    let old_values = ConfigValues::from(&**config);
+
+    action.apply(config, current_time)?;
+
+    match action {
+        ConfigAction::CreateFeeWaiver { payer, discount_percent, expires_at } => {
+            let (fee_waiver_info, bump) = find_fee_waiver(config, payer, accounts.fee_waiver)?;
+
+            create_pda_account(
+                accounts.authority,
+                fee_waiver_info,
+                accounts.system_program,
+                std::mem::size_of::<FeeWaiver>() + 8,
+                &[FEE_WAIVER_TAG, config.key().as_ref(), payer.as_ref(), &[bump]],
+            )?;
+
+            let mut fee_waiver = FeeWaiver { bump, config: config.key(), payer: *payer, created_at: current_time, ..Default::default() };
+            fee_waiver.set_discount(*discount_percent, *expires_at)?;
+            fee_waiver.try_serialize(&mut &mut fee_waiver_info.try_borrow_mut_data()?[..])?;
+        }
+        ConfigAction::UpdateFeeWaiver { payer, discount_percent, expires_at } => {
+            let (fee_waiver_info, _) = find_fee_waiver(config, payer, accounts.fee_waiver)?;
+
+            let mut fee_waiver = FeeWaiver::try_deserialize(&mut &fee_waiver_info.try_borrow_data()?[..])?;
+            fee_waiver.set_discount(*discount_percent, *expires_at)?;
+            fee_waiver.try_serialize(&mut &mut fee_waiver_info.try_borrow_mut_data()?[..])?;
+        }
+        ConfigAction::RemoveFeeWaiver { payer } => {
+            let (fee_waiver_info, _) = find_fee_waiver(config, payer, accounts.fee_waiver)?;
+
+            FeeWaiver::try_deserialize(&mut &fee_waiver_info.try_borrow_data()?[..])?; // must exist
+            close_account(fee_waiver_info, accounts.rent_receiver)?;
+        }
+        ConfigAction::Close => {
+            emit!(ConfigClosed {
+                config: config.key(),
+                authority: accounts.authority.key(),
+                program: config.program,
+                fee_instruction_index: config.fee_instruction_index,
+                rent_receiver: accounts.rent_receiver.key(),
+                closed_at: current_time,
+            });
+
+            config.close(accounts.rent_receiver.clone())?;
+        }
+        _ => {
+            emit!(ConfigUpdated {
+                config: config.key(),
+                authority: accounts.authority.key(),
+                program: config.program,
+                fee_instruction_index: config.fee_instruction_index,
+                old_values,
+                new_values: ConfigValues::from(&**config),
+                updated_at: current_time,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// the fee waiver PDA of payer on config, which must be passed as fee_waiver
+fn find_fee_waiver<'a, 'info>(
+    config: &Account<'info, Config>,
+    payer: &Pubkey,
+    fee_waiver: Option<&'a UncheckedAccount<'info>>,
+) -> Result<(&'a AccountInfo<'info>, u8)> {
+    let (address, bump) = Pubkey::find_program_address(&[FEE_WAIVER_TAG, config.key().as_ref(), payer.as_ref()], &crate::ID);
+    let fee_waiver = fee_waiver.filter(|fee_waiver| fee_waiver.key() == address).ok_or(ContractError::FeeWaiverRequired)?;
+
+    Ok((fee_waiver, bump))
+}
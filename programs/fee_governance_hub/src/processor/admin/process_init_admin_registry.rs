@@ -0,0 +1,119 @@
+use {
+    crate::{constant::*, error::ContractError, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct InitAdminRegistryCtx<'info> {
+    #[account(
+      mut,
+      constraint = is_bootstrap_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AdminRegistry::space(),
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAdminRegistryCtx>) -> Result<()> {
+    ctx.accounts.admin_registry.init(ctx.bumps.admin_registry, Clock::get()?.unix_timestamp as u64);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::str::FromStr};
+
+    #[test]
+    fn init_migrates_bootstrap_admins() {
+        let mut admin_registry = AdminRegistry::default();
+        admin_registry.init(255, 1_700_000_000);
+
+        assert_eq!(admin_registry.admins.len(), ADMINS.len());
+        for admin in ADMINS {
+            let admin = Pubkey::from_str(admin).unwrap();
+            assert!(admin_registry.is_admin(&admin));
+            assert!(is_bootstrap_admin(&admin));
+        }
+        assert_eq!(admin_registry.bump, 255);
+        assert_eq!(admin_registry.created_at, 1_700_000_000);
+        assert!(!admin_registry.has_pending_admin());
+        assert!(!admin_registry.is_proposal_required());
+        assert!(!admin_registry.is_timelock_required());
+    }
+
+    #[test]
+    fn bootstrap_admins_fit_registry() {
+        let bootstrap_admins = get_bootstrap_admins();
+        assert!(!bootstrap_admins.is_empty() && bootstrap_admins.len() <= MAX_ADMINS_LEN);
+        for (index, admin) in bootstrap_admins.iter().enumerate() {
+            assert!(!bootstrap_admins[..index].contains(admin));
+        }
+
+        let admin_registry = AdminRegistry {
+            admins: vec![Pubkey::new_unique(); MAX_ADMINS_LEN],
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        admin_registry.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= AdminRegistry::space());
+    }
+
+    #[test]
+    fn get_admin_index_of_unknown_admin_is_none() {
+        let mut admin_registry = AdminRegistry::default();
+        admin_registry.init(255, 0);
+
+        assert_eq!(admin_registry.get_admin_index(&admin_registry.admins[0]), Some(0));
+        assert_eq!(admin_registry.get_admin_index(&Pubkey::new_unique()), None);
+    }
+
+    #[test]
+    fn propose_admin_keeps_handover_of_other_admin() {
+        let (first_admin, second_admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut admin_registry = AdminRegistry {
+            admins: vec![first_admin, second_admin],
+            ..Default::default()
+        };
+
+        let new_admin = Pubkey::new_unique();
+        admin_registry.propose_admin(first_admin, new_admin).unwrap();
+        assert_eq!(
+            admin_registry.propose_admin(second_admin, Pubkey::new_unique()).unwrap_err(),
+            ContractError::PendingAdminExists.into()
+        );
+        assert_eq!((admin_registry.pending_admin, admin_registry.proposed_by), (new_admin, first_admin));
+
+        let replaced_admin = Pubkey::new_unique();
+        admin_registry.propose_admin(first_admin, replaced_admin).unwrap();
+        assert_eq!(admin_registry.pending_admin, replaced_admin);
+    }
+
+    #[test]
+    fn accept_admin_rejects_existing_admin() {
+        let (first_admin, second_admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut admin_registry = AdminRegistry {
+            admins: vec![first_admin],
+            ..Default::default()
+        };
+
+        admin_registry.propose_admin(first_admin, second_admin).unwrap();
+        admin_registry.add_admin(second_admin).unwrap();
+        assert_eq!(admin_registry.accept_admin().unwrap_err(), ContractError::AdminAlreadyExists.into());
+
+        admin_registry.remove_admin(&second_admin).unwrap();
+        admin_registry.accept_admin().unwrap();
+        assert_eq!(admin_registry.admins, vec![second_admin]);
+        assert!(!admin_registry.has_pending_admin());
+    }
+}
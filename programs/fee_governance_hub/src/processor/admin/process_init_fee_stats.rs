@@ -0,0 +1,50 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct InitFeeStatsCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = FeeStats::space(),
+        seeds = [FEE_STATS_TAG, config.key().as_ref()],
+        bump,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitFeeStatsCtx>) -> Result<()> {
+    let fee_stats = &mut ctx.accounts.fee_stats;
+
+    fee_stats.bump = ctx.bumps.fee_stats;
+    fee_stats.config = ctx.accounts.config.key();
+    fee_stats.wallet_totals = Vec::new();
+
+    fee_stats.created_at = Clock::get()?.unix_timestamp as u64;
+
+    ctx.accounts.config.has_fee_stats = true;
+
+    Ok(())
+}
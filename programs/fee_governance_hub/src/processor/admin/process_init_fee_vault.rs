@@ -0,0 +1,72 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct InitFeeVaultCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = FeeVault::space(),
+        seeds = [FEE_VAULT_TAG, config.key().as_ref()],
+        bump,
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    // token fees only
+    #[account(constraint = fee_mint.key() == config.fee_mint @ ContractError::InvalidFeeMint)]
+    pub fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = fee_mint,
+        token::authority = fee_vault,
+        token::token_program = token_program,
+        seeds = [FEE_VAULT_TAG, fee_vault.key().as_ref(), config.fee_mint.as_ref()],
+        bump,
+    )]
+    pub fee_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitFeeVaultCtx>) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    if config.is_token_fee() {
+        require!(ctx.accounts.fee_vault_token_account.is_some(), ContractError::TokenAccountsRequired);
+    }
+
+    let fee_vault = &mut ctx.accounts.fee_vault;
+
+    fee_vault.bump = ctx.bumps.fee_vault;
+    fee_vault.config = config.key();
+    fee_vault.fee_mint = config.fee_mint;
+    fee_vault.balances = Vec::new();
+
+    fee_vault.created_at = Clock::get()?.unix_timestamp as u64;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateConfigIx {
+    fee_instruction_index: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: MigrateConfigIx)]
+pub struct MigrateConfigCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>, // anyone, pays the rent of the extra space
+
+    /// CHECK: A legacy layout doesn't deserialize as Config, it is read with Config::try_deserialize_versioned
+    #[account(
+        mut,
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateConfigCtx>, _ix: MigrateConfigIx) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+
+    let mut config = Config::try_deserialize_versioned(&config_info.try_borrow_data()?)?;
+    require!(!config.is_current_version(), ContractError::ConfigAlreadyMigrated);
+
+    let old_version = config.version;
+    config.version = CONFIG_VERSION;
+
+    let space = Config::space(config.fee_wallets.len());
+    if config_info.data_len() < space {
+        resize_account(&config_info, &ctx.accounts.payer.to_account_info(), &ctx.accounts.system_program.to_account_info(), space)?;
+    }
+
+    let mut data = config_info.try_borrow_mut_data()?;
+    data.fill(0); // leftovers of the legacy layout would be read as new fields
+    config.try_serialize(&mut &mut data[..])?;
+
+    emit!(ConfigMigrated {
+        config: config_info.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        old_version,
+        new_version: config.version,
+        migrated_at: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeAdminIx {
+    new_admin: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: ProposeAdminIx)]
+pub struct ProposeAdminCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+}
+
+// Step 1 of the handover: authority proposes new_admin as its replacement.
+pub fn handler(ctx: Context<ProposeAdminCtx>, ix: ProposeAdminIx) -> Result<()> {
+    ctx.accounts.admin_registry.propose_admin(ctx.accounts.authority.key(), ix.new_admin)
+}
@@ -0,0 +1,35 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct RemoveFeeWaiverCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [FEE_WAIVER_TAG, fee_waiver.config.as_ref(), fee_waiver.payer.as_ref()],
+        bump = fee_waiver.bump,
+        close = authority,
+    )]
+    pub fee_waiver: Box<Account<'info, FeeWaiver>>,
+}
+
+pub fn handler(ctx: Context<RemoveFeeWaiverCtx>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    Ok(())
+}
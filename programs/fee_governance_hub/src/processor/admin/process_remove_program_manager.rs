@@ -0,0 +1,34 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction()]
+pub struct RemoveProgramManagerCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [PROGRAM_MANAGER_TAG, program_manager.program.as_ref(), program_manager.manager.as_ref()],
+        bump = program_manager.bump,
+        close = authority,
+    )]
+    pub program_manager: Box<Account<'info, ProgramManager>>,
+}
+
+pub fn handler(ctx: Context<RemoveProgramManagerCtx>) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ScheduleConfigUpdateIx {
+    pub fee_instruction_index: u64, // of the config
+    pub action: ConfigAction,
+    pub effective_at: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: ScheduleConfigUpdateIx)]
+pub struct ScheduleConfigUpdateCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // an admin, or a program manager of target_program for its Update actions
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        seeds = [PROGRAM_MANAGER_TAG, target_program.key().as_ref(), authority.key().as_ref()],
+        bump = program_manager.bump,
+    )]
+    pub program_manager: Option<Box<Account<'info, ProgramManager>>>, // program managers only
+
+    #[account(
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingConfigUpdate::space(&ix.action),
+        seeds = [PENDING_CONFIG_UPDATE_TAG, config.key().as_ref()],
+        bump,
+    )]
+    pub pending_update: Box<Account<'info, PendingConfigUpdate>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ScheduleConfigUpdateCtx>, ix: ScheduleConfigUpdateIx) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    if ctx.accounts.admin_registry.is_admin(ctx.accounts.authority.key) {
+        require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    } else {
+        require!(ctx.accounts.program_manager.is_some(), ContractError::InvalidAuthority);
+        validate_manager_action(&ix.action, config)?;
+    }
+
+    if let ConfigAction::Update(payload) = &ix.action {
+        require!(payload.fee_instruction_index == ix.fee_instruction_index, ContractError::InvalidConfig);
+    }
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let mut new_config = (***config).clone();
+    ix.action.apply(&mut new_config, current_time)?; // reject invalid actions up front
+
+    require!(
+        ix.effective_at >= current_time.checked_add(ctx.accounts.admin_registry.min_update_delay).ok_or(ContractError::MathOverflow)?,
+        ContractError::InvalidEffectiveAt
+    );
+
+    let pending_update = &mut ctx.accounts.pending_update;
+
+    pending_update.bump = ctx.bumps.pending_update;
+    pending_update.config = config.key();
+    pending_update.scheduled_by = ctx.accounts.authority.key();
+    pending_update.action = ix.action.clone();
+    pending_update.effective_at = ix.effective_at;
+
+    pending_update.created_at = current_time;
+
+    emit!(ConfigUpdateScheduled {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        action: ix.action,
+        old_values: ConfigValues::from(&***config),
+        new_values: ConfigValues::from(&new_config),
+        effective_at: pending_update.effective_at,
+        scheduled_at: current_time,
+    });
+
+    Ok(())
+}
+
+// program managers can only schedule what manager_update_config accepts
+pub fn validate_manager_action(action: &ConfigAction, config: &Config) -> Result<()> {
+    match action {
+        ConfigAction::Update(payload) => payload.validate_manager_update(config),
+        _ => err!(ContractError::InvalidAuthority),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::processor::UpdateConfigIx};
+
+    #[test]
+    fn managers_can_only_schedule_manager_updates() {
+        let config = Config { version: CONFIG_VERSION, ..Default::default() };
+        let payload = UpdateConfigIx { fee_wallets: vec![FeeWallet { address: Pubkey::new_unique(), fee_percent: PERCENT_DENOMINATOR }], ..Default::default() };
+        validate_manager_action(&ConfigAction::Update(payload.clone()), &config).unwrap();
+
+        let payload = UpdateConfigIx { fee_amount: 1, ..payload };
+        assert_eq!(
+            validate_manager_action(&ConfigAction::Update(payload), &config).unwrap_err(),
+            ContractError::ConfigFieldAdminOnly.into()
+        );
+        assert_eq!(
+            validate_manager_action(&ConfigAction::Close, &config).unwrap_err(),
+            ContractError::InvalidAuthority.into()
+        );
+    }
+}
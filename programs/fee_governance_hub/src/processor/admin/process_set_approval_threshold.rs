@@ -0,0 +1,33 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetApprovalThresholdIx {
+    approval_threshold: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: SetApprovalThresholdIx)]
+pub struct SetApprovalThresholdCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+}
+
+pub fn handler(ctx: Context<SetApprovalThresholdCtx>, ix: SetApprovalThresholdIx) -> Result<()> {
+    let admin_registry = &mut ctx.accounts.admin_registry;
+
+    require!(!admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+
+    admin_registry.set_approval_threshold(ix.approval_threshold)
+}
@@ -0,0 +1,59 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetConfigActiveIx {
+    fee_instruction_index: u64,
+    is_active: bool,
+    inactive_fee_policy: InactiveFeePolicy,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: SetConfigActiveIx)]
+pub struct SetConfigActiveCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SetConfigActiveCtx>, ix: SetConfigActiveIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    let config = &mut ctx.accounts.config;
+    let old_values = ConfigValues::from(&***config);
+
+    config.set_active(ix.is_active, ix.inactive_fee_policy);
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        old_values,
+        new_values: ConfigValues::from(&***config),
+        updated_at: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
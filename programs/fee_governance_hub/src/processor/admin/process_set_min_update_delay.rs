@@ -0,0 +1,40 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMinUpdateDelayIx {
+    min_update_delay: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: SetMinUpdateDelayIx)]
+pub struct SetMinUpdateDelayCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+}
+
+pub fn handler(ctx: Context<SetMinUpdateDelayCtx>, ix: SetMinUpdateDelayIx) -> Result<()> {
+    let admin_registry = &mut ctx.accounts.admin_registry;
+
+    require!(!admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    // lowering the notice period takes an admin proposal, which waits the current delay
+    require!(
+        !admin_registry.is_timelock_required() || ix.min_update_delay >= admin_registry.min_update_delay,
+        ContractError::TimelockRequired
+    );
+
+    admin_registry.min_update_delay = ix.min_update_delay;
+
+    Ok(())
+}
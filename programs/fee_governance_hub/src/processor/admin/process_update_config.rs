@@ -1,15 +1,24 @@
 use {
-    crate::{constant::*, error::ContractError, state::*, utils::*},
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
     anchor_lang::prelude::*,
 };
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Default)]
 pub struct UpdateConfigIx {
-    fee_instruction_index: u64,
-    is_using_global_fee_wallets: bool,
-    fee_wallets: [FeeWallet; MAX_FEE_WALLETS_LEN],
-    fee_amount: u64,
-    fee_instruction_name: String,
+    pub fee_instruction_index: u64,
+    pub is_using_global_fee_wallets: bool,
+    pub fee_wallets: Vec<FeeWallet>,
+    pub fee_amount: u64,
+    pub fee_instruction_name: String,
+    pub fee_mode: FeeMode,
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+    pub fee_mint: Pubkey,
+    pub remainder_policy: RemainderPolicy,
+    pub remainder_treasury: Pubkey,
+    pub is_accrual_mode: bool,
+    pub platform_share_percent: u64,
 }
 
 #[derive(Accounts)]
@@ -17,28 +26,100 @@ pub struct UpdateConfigIx {
 pub struct UpdateConfigCtx<'info> {
     #[account(
       mut,
-      constraint = is_admin(authority.key) @ ContractError::InvalidAuthority
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
     )]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
     #[account(
         mut,
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
         seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
         bump,
+        realloc = ix.space(),
+        realloc::payer = authority,
+        realloc::zero = false,
     )]
     pub config: Box<Account<'info, Config>>,
 
     /// CHECK: We read this key only
     pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl UpdateConfigIx {
+    // config account size once this payload is applied
+    pub fn space(&self) -> usize {
+        Config::space(compact_fee_wallets(&self.fee_wallets).len())
+    }
+
+    // program managers may only change where the fees go
+    pub fn is_manager_update(&self, config: &Config) -> bool {
+        self.is_using_global_fee_wallets == config.is_using_global_fee_wallets
+            && self.fee_amount == config.fee_amount
+            && self.fee_instruction_name == config.fee_instruction_name
+            && self.fee_mode == config.fee_mode
+            && self.fee_bps == config.fee_bps
+            && self.min_fee_amount == config.min_fee_amount
+            && self.max_fee_amount == config.max_fee_amount
+            && self.fee_mint == config.fee_mint
+            && self.is_accrual_mode == config.is_accrual_mode
+            && self.platform_share_percent == config.platform_share_percent
+    }
+
+    // fees of configs using the global fee wallets and the platform share go to the platform, so only admins may change them
+    pub fn validate_manager_update(&self, config: &Config) -> Result<()> {
+        require!(!config.is_using_global_fee_wallets && !self.is_using_global_fee_wallets, ContractError::GlobalFeeWalletsAdminOnly);
+        require!(self.is_manager_update(config), ContractError::ConfigFieldAdminOnly);
+
+        Ok(())
+    }
+
+    pub fn apply(&self, config: &mut Config) -> Result<()> {
+        config.is_using_global_fee_wallets = self.is_using_global_fee_wallets;
+        config.fee_wallets = compact_fee_wallets(&self.fee_wallets);
+        config.fee_amount = self.fee_amount;
+        config.fee_instruction_name.clone_from(&self.fee_instruction_name);
+        config.fee_mode = self.fee_mode;
+        config.fee_bps = self.fee_bps;
+        config.min_fee_amount = self.min_fee_amount;
+        config.max_fee_amount = self.max_fee_amount;
+        config.fee_mint = self.fee_mint;
+        config.remainder_policy = self.remainder_policy;
+        config.remainder_treasury = self.remainder_treasury;
+        config.is_accrual_mode = self.is_accrual_mode;
+        config.platform_share_percent = self.platform_share_percent;
+
+        config.validate()?;
+
+        Ok(())
+    }
 }
 
 pub fn handler(ctx: Context<UpdateConfigCtx>, ix: UpdateConfigIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
     let config = &mut ctx.accounts.config;
+    let old_values = ConfigValues::from(&***config);
+
+    ix.apply(config)?;
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        old_values,
+        new_values: ConfigValues::from(&***config),
+        updated_at: Clock::get()?.unix_timestamp as u64,
+    });
 
-    config.is_using_global_fee_wallets = ix.is_using_global_fee_wallets;
-    config.fee_wallets = ix.fee_wallets.to_vec();
-    config.fee_amount = ix.fee_amount;
-    config.fee_instruction_name = ix.fee_instruction_name;
-    
     Ok(())
 }
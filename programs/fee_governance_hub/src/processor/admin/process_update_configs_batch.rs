@@ -0,0 +1,72 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, processor::UpdateConfigIx, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateConfigsBatchIx {
+    entries: Vec<UpdateConfigIx>,
+}
+
+// remaining accounts: the config PDA of each entry, writable, in entry order
+#[derive(Accounts)]
+pub struct UpdateConfigsBatchCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, UpdateConfigsBatchCtx<'info>>, ix: UpdateConfigsBatchIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+    require!(!ix.entries.is_empty() && ix.entries.len() <= MAX_CONFIG_BATCH_LEN, ContractError::InvalidConfigBatch);
+    require!(ctx.remaining_accounts.len() == ix.entries.len(), ContractError::InvalidRemainingAccounts);
+
+    let target_program = ctx.accounts.target_program.key();
+    let authority = ctx.accounts.authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let updated_at = Clock::get()?.unix_timestamp as u64;
+
+    for (entry, config_info) in ix.entries.iter().zip(ctx.remaining_accounts) {
+        require!(config_info.is_writable, ContractError::InvalidRemainingAccounts);
+
+        let mut config = Account::<Config>::try_from(config_info)?;
+        require!(
+            config.program == target_program && config.is_config_address(config_info.key, entry.fee_instruction_index),
+            ContractError::InvalidRemainingAccounts
+        );
+        require!(config.is_current_version(), ContractError::ConfigMigrationRequired);
+
+        let old_values = ConfigValues::from(&*config);
+
+        entry.apply(&mut config)?;
+
+        resize_account(config_info, &authority, &system_program, entry.space())?;
+        config.exit(&crate::ID)?;
+
+        emit!(ConfigUpdated {
+            config: config_info.key(),
+            authority: authority.key(),
+            program: config.program,
+            fee_instruction_index: config.fee_instruction_index,
+            old_values,
+            new_values: ConfigValues::from(&*config),
+            updated_at,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use {
+    crate::{constant::*, error::ContractError, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateFeeWaiverIx {
+    discount_percent: u64,
+    expires_at: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: UpdateFeeWaiverIx)]
+pub struct UpdateFeeWaiverCtx<'info> {
+    #[account(
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [FEE_WAIVER_TAG, fee_waiver.config.as_ref(), fee_waiver.payer.as_ref()],
+        bump = fee_waiver.bump,
+    )]
+    pub fee_waiver: Box<Account<'info, FeeWaiver>>,
+}
+
+pub fn handler(ctx: Context<UpdateFeeWaiverCtx>, ix: UpdateFeeWaiverIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    ctx.accounts.fee_waiver.set_discount(ix.discount_percent, ix.expires_at)
+}
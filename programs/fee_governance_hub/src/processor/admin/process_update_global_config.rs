@@ -0,0 +1,54 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*, utils::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGlobalConfigIx {
+    fee_wallets: Vec<FeeWallet>,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: UpdateGlobalConfigIx)]
+pub struct UpdateGlobalConfigCtx<'info> {
+    #[account(
+      mut,
+      constraint = admin_registry.is_admin(authority.key) @ ContractError::InvalidAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump = global_config.bump,
+        realloc = GlobalConfig::space(compact_fee_wallets(&ix.fee_wallets).len()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateGlobalConfigCtx>, ix: UpdateGlobalConfigIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_proposal_required(), ContractError::ProposalRequired);
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    let global_config = &mut ctx.accounts.global_config;
+    let old_fee_wallets = global_config.update(&ix.fee_wallets, Clock::get()?.unix_timestamp as u64)?;
+
+    emit!(GlobalConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        old_fee_wallets,
+        new_fee_wallets: global_config.fee_wallets.clone(),
+        updated_at: global_config.updated_at,
+    });
+
+    Ok(())
+}
@@ -1,2 +1,7 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod process_transfer_fees;
 pub use process_transfer_fees::*;
+
+pub mod process_quote_fees;
+pub use process_quote_fees::*;
@@ -0,0 +1,59 @@
+use {
+    crate::{constant::*, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QuoteFeesIx {
+    pub fee_instruction_index: u64,
+    pub base_amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: QuoteFeesIx)]
+pub struct QuoteFeesCtx<'info> {
+    // config of the instruction, or the default config of target_program. Checked in handler.
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: must be empty, only passed when falling back to the default config
+    #[account(
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+    )]
+    pub fee_instruction_config: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump = global_config.bump,
+    )]
+    pub global_config: Option<Box<Account<'info, GlobalConfig>>>,
+
+    #[account(
+        seeds = [FEE_WAIVER_TAG, config.key().as_ref(), payer.key().as_ref()],
+        bump = fee_waiver.bump,
+    )]
+    pub fee_waiver: Option<Box<Account<'info, FeeWaiver>>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    /// CHECK: We read this key only
+    pub payer: UncheckedAccount<'info>,
+}
+
+// Read-only. Simulate it to get what transfer_fees would charge payer.
+pub fn handler(ctx: Context<QuoteFeesCtx>, ix: QuoteFeesIx) -> Result<FeeQuote> {
+    ctx.accounts.config.validate_for_instruction(
+        &ctx.accounts.config.key(),
+        ctx.accounts.target_program.key,
+        ix.fee_instruction_index,
+        ctx.accounts.fee_instruction_config.as_ref().map(|fee_instruction_config| fee_instruction_config.as_ref()),
+    )?;
+
+    ctx.accounts.config.quote_fees(
+        ctx.accounts.global_config.as_ref().map(|global_config| &***global_config),
+        ctx.accounts.fee_waiver.as_ref().map(|fee_waiver| &***fee_waiver),
+        ix.base_amount,
+        Clock::get()?.unix_timestamp as u64,
+    )
+}
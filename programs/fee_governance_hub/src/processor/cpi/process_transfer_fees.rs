@@ -1,79 +1,233 @@
 use {
-    crate::{constant::*, error::ContractError, state::*, utils::*},
-    anchor_lang::prelude::*,
-    std::str::FromStr,
+    crate::{constant::*, error::ContractError, event::*, state::*},
+    anchor_lang::{prelude::*, Discriminator},
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct TransferFeesIx {
     pub fee_instruction_index: u64,
+    pub base_amount: u64, // value of the action, used by Bps and FlatPlusBps fee modes
 }
 
 #[derive(Accounts)]
 #[instruction(ix: TransferFeesIx)]
 pub struct TransferFeesCtx<'info> {
-    #[account(
-      mut,
-      constraint = is_admin(authority.key) @ ContractError::InvalidAuthority
-    )]
-    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>, // end user of target_program, pays the fees
+
+    // config of the instruction, or the default config of target_program. Checked in handler.
+    pub config: Box<Account<'info, Config>>,
 
+    /// CHECK: must be empty, only passed when falling back to the default config
     #[account(
         seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
         bump,
     )]
-    pub config: Box<Account<'info, Config>>,
+    pub fee_instruction_config: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [GLOBAL_CONFIG_TAG],
+        bump = global_config.bump,
+    )]
+    pub global_config: Option<Box<Account<'info, GlobalConfig>>>,
 
     /// CHECK: We read this key only
     pub target_program: UncheckedAccount<'info>,
 
+    // PDA of target_program. Only target_program can sign for it, so fees are only charged from its instructions.
+    #[account(
+        seeds = [FEE_CALLER_TAG],
+        bump,
+        seeds::program = target_program.key(),
+    )]
+    pub fee_caller: Signer<'info>,
+
+    // token fees only
+    pub fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(mut)]
+    pub payer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // accrual mode only
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_TAG, config.key().as_ref()],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Option<Box<Account<'info, FeeVault>>>,
+
+    #[account(mut)]
+    pub fee_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>, // token fees only
+
+    #[account(
+        mut,
+        seeds = [FEE_STATS_TAG, config.key().as_ref()],
+        bump = fee_stats.bump,
+    )]
+    pub fee_stats: Option<Box<Account<'info, FeeStats>>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, TransferFeesCtx<'info>>, _: TransferFeesIx) -> Result<()> {
-    let config = &ctx.accounts.config;
-    
-    let mut fee_wallets = Vec::new();
-    if config.is_using_global_fee_wallets {
-        for i in 0..GLOBAL_FEE_WALLETS.len() {
-            let fee_wallet = FeeWallet {
-                address: Pubkey::from_str(GLOBAL_FEE_WALLETS[i]).unwrap(),
-                fee_percent: GLOBAL_FEE_WALLETS_FEE_PERCENT[i],
-            };
-            fee_wallets.push(fee_wallet);
+impl<'info> TransferFeesCtx<'info> {
+    // pays amount to recipient, the wallet itself for SOL fees or its token account for token fees
+    fn pay(&self, recipient: &AccountInfo<'info>, recipient_wallet: Pubkey, amount: u64) -> Result<()> {
+        if self.config.is_token_fee() {
+            let fee_mint = self.fee_mint.as_ref().unwrap();
+            let token_program = self.token_program.as_ref().unwrap();
+
+            require!(recipient.owner == &token_program.key(), ContractError::InvalidFeeWallet);
+            let recipient_token_account = TokenAccount::try_deserialize(&mut &recipient.data.borrow()[..])?;
+
+            if recipient_token_account.owner != recipient_wallet || recipient_token_account.mint != fee_mint.key() {
+                return Err(ContractError::InvalidFeeWallet.into());
+            }
+
+            anchor_spl::token_interface::transfer_checked(
+                CpiContext::new(token_program.to_account_info(),
+                TransferChecked {
+                    from: self.payer_token_account.as_ref().unwrap().to_account_info(),
+                    mint: fee_mint.to_account_info(),
+                    to: recipient.to_account_info(),
+                    authority: self.payer.to_account_info(),
+                }
+            ), amount, fee_mint.decimals)
+        } else {
+            if recipient_wallet != recipient.key() {
+                return Err(ContractError::InvalidFeeWallet.into());
+            }
+
+            anchor_lang::system_program::transfer(
+                CpiContext::new(self.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: self.payer.to_account_info(),
+                    to: recipient.to_account_info(),
+                }
+            ), amount)
         }
-    } else {
-        for i in 0..config.fee_wallets.len() {
-            let fee_wallet = FeeWallet {
-                address: config.fee_wallets[i].address,
-                fee_percent: config.fee_wallets[i].fee_percent,
-            };
-            fee_wallets.push(fee_wallet);
+    }
+
+    // pays amount to the fee vault in one transfer, fee wallets claim their shares later
+    fn deposit(&self, amount: u64) -> Result<()> {
+        let fee_vault = self.fee_vault.as_ref().ok_or(ContractError::FeeVaultRequired)?;
+        require!(fee_vault.fee_mint == self.config.fee_mint, ContractError::InvalidFeeMint);
+
+        if self.config.is_token_fee() {
+            let fee_vault_token_account = self.fee_vault_token_account.as_ref().ok_or(ContractError::TokenAccountsRequired)?;
+            let (fee_vault_token_account_key, _) = Pubkey::find_program_address(
+                &[FEE_VAULT_TAG, fee_vault.key().as_ref(), fee_vault.fee_mint.as_ref()],
+                &crate::ID,
+            );
+            require!(fee_vault_token_account.key() == fee_vault_token_account_key, ContractError::FeeVaultRequired);
+
+            self.pay(&fee_vault_token_account.to_account_info(), fee_vault.key(), amount)
+        } else {
+            self.pay(&fee_vault.to_account_info(), fee_vault.key(), amount)
         }
     }
+}
+
+// Splits off a trailing FeeWaiver of payer on config. Other accounts, including other accounts of this program,
+// are left for the fee wallets.
+fn find_fee_waiver<'a, 'info>(remaining_accounts: &'a [AccountInfo<'info>], config: Pubkey, payer: Pubkey) -> Result<(&'a [AccountInfo<'info>], Option<FeeWaiver>)> {
+    if let Some((last_account, other_accounts)) = remaining_accounts.split_last() {
+        if last_account.owner == &crate::ID && last_account.data.borrow().starts_with(&FeeWaiver::DISCRIMINATOR) {
+            let fee_waiver = FeeWaiver::try_deserialize(&mut &last_account.data.borrow()[..])?;
+            require!(fee_waiver.config == config && fee_waiver.payer == payer, ContractError::InvalidRemainingAccounts);
+            return Ok((other_accounts, Some(fee_waiver)));
+        }
+    }
+
+    Ok((remaining_accounts, None))
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, TransferFeesCtx<'info>>, ix: TransferFeesIx) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.validate_for_instruction(
+        &config.key(),
+        ctx.accounts.target_program.key,
+        ix.fee_instruction_index,
+        ctx.accounts.fee_instruction_config.as_ref().map(|fee_instruction_config| fee_instruction_config.as_ref()),
+    )?;
+    require!(!config.has_fee_stats || ctx.accounts.fee_stats.is_some(), ContractError::FeeStatsRequired);
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    // the payer's FeeWaiver, if any, is the last remaining account
+    let (remaining_accounts, fee_waiver) = find_fee_waiver(ctx.remaining_accounts, config.key(), ctx.accounts.payer.key())?;
+
+    let fee_quote = config.quote_fees(
+        ctx.accounts.global_config.as_ref().map(|global_config| &***global_config),
+        fee_waiver.as_ref(),
+        ix.base_amount,
+        current_time,
+    )?;
 
-    let mut accumalated_percent: u64 = 0;
-    for (index, account) in ctx.remaining_accounts.iter().enumerate() {
-        let fee_wallet_info = fee_wallets[index].clone();
-        
-        if fee_wallet_info.address != account.key() {
-            return Err(ContractError::InvalidFeeWallet.into());
+    if !fee_quote.is_waived {
+        if config.is_token_fee() {
+            require!(
+                ctx.accounts.fee_mint.is_some() && ctx.accounts.payer_token_account.is_some() && ctx.accounts.token_program.is_some(),
+                ContractError::TokenAccountsRequired
+            );
+            let fee_mint = ctx.accounts.fee_mint.as_ref().unwrap();
+            require!(fee_mint.key() == config.fee_mint, ContractError::InvalidFeeMint);
         }
 
-        let fee_amount = fee_wallet_info.fee_percent.checked_mul(ctx.accounts.config.fee_amount).unwrap().checked_div(PERCENT_DENOMINATOR).unwrap();
+        if config.is_accrual_mode {
+            ctx.accounts.deposit(fee_quote.total_fee_amount)?;
+        } else {
+            // with the Treasury policy, the last remaining account is the remainder treasury
+            let (fee_wallet_accounts, treasury_account) = if fee_quote.treasury.is_some() {
+                let (treasury_account, fee_wallet_accounts) = remaining_accounts.split_last().ok_or(ContractError::InvalidRemainingAccounts)?;
+                (fee_wallet_accounts, Some(treasury_account))
+            } else {
+                (remaining_accounts, None)
+            };
+
+            require!(fee_wallet_accounts.len() == fee_quote.fee_wallets.len(), ContractError::InvalidRemainingAccounts);
+
+            for (account, fee_transfer) in fee_wallet_accounts.iter().zip(fee_quote.fee_wallets.iter()) {
+                ctx.accounts.pay(account, fee_transfer.wallet, fee_transfer.amount)?;
+            }
 
-        anchor_lang::system_program::transfer(
-            CpiContext::new(ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.authority.to_account_info(),
-                to: account.to_account_info(),
+            if let (Some(treasury_account), Some(treasury)) = (treasury_account, fee_quote.treasury.as_ref()) {
+                ctx.accounts.pay(treasury_account, treasury.wallet, treasury.amount)?;
             }
-        ), fee_amount)?;
+        }
+    }
 
-        accumalated_percent = accumalated_percent.checked_add(fee_wallet_info.fee_percent).unwrap();
+    if let Some(fee_stats) = ctx.accounts.fee_stats.as_mut() {
+        fee_stats.record(&fee_quote, current_time)?;
+    }
+
+    let mut transfers = fee_quote.fee_wallets;
+    transfers.extend(fee_quote.treasury);
+
+    let is_accrued = config.is_accrual_mode && !fee_quote.is_waived;
+    if is_accrued {
+        let fee_vault = ctx.accounts.fee_vault.as_mut().unwrap();
+        for fee_transfer in transfers.iter() {
+            fee_vault.credit(fee_transfer.wallet, fee_transfer.amount)?;
+        }
     }
 
-    require!(accumalated_percent == PERCENT_DENOMINATOR, ContractError::InvalidRemainingAccounts);
+    emit!(FeesTransferred {
+        config: config.key(),
+        payer: ctx.accounts.payer.key(),
+        target_program: ctx.accounts.target_program.key(),
+        fee_instruction_index: ix.fee_instruction_index as u8,
+        base_amount: ix.base_amount,
+        total_fee_amount: fee_quote.total_fee_amount,
+        discount_percent: fee_quote.discount_percent,
+        fee_mint: fee_quote.fee_mint,
+        transfers,
+        is_waived: fee_quote.is_waived,
+        is_accrued,
+        transferred_at: current_time,
+    });
 
     Ok(())
 }
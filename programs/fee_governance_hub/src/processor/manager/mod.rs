@@ -0,0 +1,4 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod process_manager_update_config;
+pub use process_manager_update_config::*;
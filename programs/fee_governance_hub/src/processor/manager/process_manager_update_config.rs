@@ -0,0 +1,117 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, processor::UpdateConfigIx, state::*},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(ix: UpdateConfigIx)]
+pub struct ManagerUpdateConfigCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [PROGRAM_MANAGER_TAG, target_program.key().as_ref(), authority.key().as_ref()],
+        bump = program_manager.bump,
+    )]
+    pub program_manager: Box<Account<'info, ProgramManager>>,
+
+    #[account(
+        seeds = [ADMIN_REGISTRY_TAG],
+        bump = admin_registry.bump,
+    )]
+    pub admin_registry: Box<Account<'info, AdminRegistry>>,
+
+    #[account(
+        mut,
+        constraint = config.is_current_version() @ ContractError::ConfigMigrationRequired,
+        seeds = [CONFIG_TAG, target_program.key().as_ref(), &ix.fee_instruction_index.to_le_bytes()],
+        bump,
+        realloc = ix.space(),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: We read this key only
+    pub target_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ManagerUpdateConfigCtx>, ix: UpdateConfigIx) -> Result<()> {
+    require!(!ctx.accounts.admin_registry.is_timelock_required(), ContractError::TimelockRequired);
+
+    let config = &mut ctx.accounts.config;
+
+    ix.validate_manager_update(config)?;
+
+    let old_values = ConfigValues::from(&***config);
+
+    ix.apply(config)?;
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        program: config.program,
+        fee_instruction_index: config.fee_instruction_index,
+        old_values,
+        new_values: ConfigValues::from(&***config),
+        updated_at: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager_update(config: &Config) -> UpdateConfigIx {
+        UpdateConfigIx {
+            fee_instruction_index: config.fee_instruction_index as u64,
+            is_using_global_fee_wallets: config.is_using_global_fee_wallets,
+            fee_wallets: vec![FeeWallet { address: Pubkey::new_unique(), fee_percent: PERCENT_DENOMINATOR }],
+            fee_amount: config.fee_amount,
+            fee_instruction_name: config.fee_instruction_name.clone(),
+            fee_mode: config.fee_mode,
+            fee_bps: config.fee_bps,
+            min_fee_amount: config.min_fee_amount,
+            max_fee_amount: config.max_fee_amount,
+            fee_mint: config.fee_mint,
+            remainder_policy: RemainderPolicy::Treasury,
+            remainder_treasury: Pubkey::new_unique(),
+            is_accrual_mode: config.is_accrual_mode,
+            platform_share_percent: config.platform_share_percent,
+        }
+    }
+
+    #[test]
+    fn manager_may_change_fee_wallets_and_remainder() {
+        let config = Config { fee_amount: 100, fee_instruction_name: "flip".to_string(), ..Default::default() };
+
+        assert!(manager_update(&config).is_manager_update(&config));
+    }
+
+    #[test]
+    fn manager_may_not_change_fee() {
+        let config = Config { fee_amount: 100, fee_instruction_name: "flip".to_string(), ..Default::default() };
+
+        let cases: Vec<fn(&mut UpdateConfigIx)> = vec![
+            |ix| ix.fee_amount = 1_000_000,
+            |ix| ix.fee_instruction_name = "other".to_string(),
+            |ix| ix.fee_mode = FeeMode::Bps,
+            |ix| ix.fee_bps = 100,
+            |ix| ix.min_fee_amount = 1,
+            |ix| ix.max_fee_amount = 1,
+            |ix| ix.fee_mint = Pubkey::new_unique(),
+            |ix| ix.is_accrual_mode = true,
+            |ix| ix.platform_share_percent = 1,
+            |ix| ix.is_using_global_fee_wallets = true,
+        ];
+        for change in cases {
+            let mut ix = manager_update(&config);
+            change(&mut ix);
+            assert!(!ix.is_manager_update(&config));
+        }
+    }
+}
@@ -1,6 +1,13 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod admin;
-#[allow(ambiguous_glob_reexports)]
 pub use admin::*;
 
 pub mod cpi;
 pub use cpi::*;
+
+pub mod user;
+pub use user::*;
+
+pub mod manager;
+pub use manager::*;
@@ -0,0 +1,4 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod process_claim_fees;
+pub use process_claim_fees::*;
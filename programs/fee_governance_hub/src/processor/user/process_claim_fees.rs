@@ -0,0 +1,108 @@
+use {
+    crate::{constant::*, error::ContractError, event::*, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct ClaimFeesCtx<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, // fee wallet
+
+    /// CHECK: We read this key only, the config may be closed already
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_TAG, config.key().as_ref()],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Box<Account<'info, FeeVault>>,
+
+    // token vaults only
+    #[account(constraint = fee_mint.key() == fee_vault.fee_mint @ ContractError::InvalidFeeMint)]
+    pub fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT_TAG, fee_vault.key().as_ref(), fee_vault.fee_mint.as_ref()],
+        bump,
+    )]
+    pub fee_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ClaimFeesCtx>) -> Result<()> {
+    let fee_vault = &mut ctx.accounts.fee_vault;
+    let amount = fee_vault.claim(ctx.accounts.authority.key())?;
+
+    let token_accounts = if fee_vault.is_token_vault() {
+        Some(FeeVaultTokenAccounts {
+            fee_mint: ctx.accounts.fee_mint.as_ref().ok_or(ContractError::TokenAccountsRequired)?,
+            fee_vault_token_account: ctx.accounts.fee_vault_token_account.as_ref().ok_or(ContractError::TokenAccountsRequired)?,
+            recipient_token_account: ctx.accounts.authority_token_account.as_ref().ok_or(ContractError::TokenAccountsRequired)?,
+            token_program: ctx.accounts.token_program.as_ref().ok_or(ContractError::TokenAccountsRequired)?,
+        })
+    } else {
+        None
+    };
+    withdraw_from_fee_vault(fee_vault, &ctx.accounts.authority.to_account_info(), token_accounts, amount)?;
+
+    emit!(FeesClaimed {
+        config: ctx.accounts.config.key(),
+        fee_vault: fee_vault.key(),
+        wallet: ctx.accounts.authority.key(),
+        fee_mint: fee_vault.fee_mint,
+        amount,
+        claimed_at: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+pub struct FeeVaultTokenAccounts<'a, 'info> { // token vaults only
+    pub fee_mint: &'a InterfaceAccount<'info, Mint>,
+    pub fee_vault_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub recipient_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+// Pays amount out of fee_vault, to recipient_token_account for token vaults and to recipient otherwise.
+pub fn withdraw_from_fee_vault<'info>(
+    fee_vault: &Account<'info, FeeVault>,
+    recipient: &AccountInfo<'info>,
+    token_accounts: Option<FeeVaultTokenAccounts<'_, 'info>>,
+    amount: u64,
+) -> Result<()> {
+    match token_accounts {
+        Some(token_accounts) => {
+            let signer_seeds: &[&[&[u8]]] = &[&[FEE_VAULT_TAG, fee_vault.config.as_ref(), &[fee_vault.bump]]];
+
+            anchor_spl::token_interface::transfer_checked(
+                CpiContext::new_with_signer(token_accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: token_accounts.fee_vault_token_account.to_account_info(),
+                    mint: token_accounts.fee_mint.to_account_info(),
+                    to: token_accounts.recipient_token_account.to_account_info(),
+                    authority: fee_vault.to_account_info(),
+                },
+                signer_seeds
+            ), amount, token_accounts.fee_mint.decimals)
+        }
+        None => {
+            // the vault is owned by this program, so lamports are moved directly
+            fee_vault.sub_lamports(amount)?;
+            recipient.add_lamports(amount)?;
+
+            Ok(())
+        }
+    }
+}
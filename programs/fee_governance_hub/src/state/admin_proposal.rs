@@ -0,0 +1,64 @@
+use {
+    crate::{constant::*, processor::CreateConfigIx, state::{AdminRegistry, Config, FeeWallet}, utils::*},
+    anchor_lang::prelude::*
+};
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub enum AdminAction { // changes that need approval_threshold admins once it is above 1
+    AddAdmin(Pubkey),
+    RemoveAdmin(Pubkey),
+    SetApprovalThreshold(u8),
+    SetMinUpdateDelay(u64),
+    UpdateGlobalConfig(Vec<FeeWallet>),
+    AddProgramManager { program: Pubkey, manager: Pubkey },
+    RemoveProgramManager { program: Pubkey, manager: Pubkey },
+    CreateConfig { program: Pubkey, payload: CreateConfigIx }, // instruction configs of programs with a default config, while the timelock is on
+}
+
+impl AdminAction {
+    // UpdateGlobalConfig and CreateConfig are only validated here and program manager actions change nothing,
+    // the caller applies them to the accounts
+    pub fn apply(&self, admin_registry: &mut AdminRegistry) -> Result<()> {
+        match self {
+            AdminAction::AddAdmin(admin) => admin_registry.add_admin(*admin),
+            AdminAction::RemoveAdmin(admin) => admin_registry.remove_admin(admin),
+            AdminAction::SetApprovalThreshold(approval_threshold) => admin_registry.set_approval_threshold(*approval_threshold),
+            AdminAction::SetMinUpdateDelay(min_update_delay) => {
+                admin_registry.min_update_delay = *min_update_delay;
+                Ok(())
+            }
+            AdminAction::UpdateGlobalConfig(fee_wallets) => validate_fee_wallets(fee_wallets),
+            AdminAction::AddProgramManager { .. } | AdminAction::RemoveProgramManager { .. } => Ok(()),
+            AdminAction::CreateConfig { program, payload } => {
+                payload.validate_fee_instruction_index(false)?;
+                payload.init(&mut Config::default(), 0, *program, 0)
+            }
+        }
+    }
+}
+
+#[account]
+pub struct AdminProposal { // Pending AdminAction awaiting admin approvals.
+    pub bump: u8,
+    pub proposal_id: u64,
+    pub proposer: Pubkey, // receives the rent back on execute or cancel
+    pub action: AdminAction,
+    pub approvals: Vec<Pubkey>, // Maximum MAX_ADMINS_LEN
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl AdminProposal {
+    pub fn space() -> usize {
+        std::mem::size_of::<AdminProposal>() + 8 + MAX_FEE_WALLETS_LEN * std::mem::size_of::<FeeWallet>() + MAX_FEE_INSTRUCTION_NAME_LEN + MAX_ADMINS_LEN * 32
+    }
+
+    pub fn has_approved(&self, admin: &Pubkey) -> bool {
+        self.approvals.contains(admin)
+    }
+
+    pub fn num_valid_approvals(&self, admin_registry: &AdminRegistry) -> usize {
+        admin_registry.num_valid_approvals(&self.approvals)
+    }
+}
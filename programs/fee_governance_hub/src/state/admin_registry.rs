@@ -0,0 +1,135 @@
+use {
+    crate::{constant::*, error::ContractError, utils::*},
+    anchor_lang::prelude::*
+};
+
+#[account]
+#[derive(Default, PartialEq, Debug)]
+pub struct AdminRegistry { // Singleton. Wallets allowed to manage configs.
+    pub bump: u8,
+    pub admins: Vec<Pubkey>, // Maximum MAX_ADMINS_LEN
+    pub pending_admin: Pubkey, // proposed by propose_admin, Pubkey::default() if none
+    pub proposed_by: Pubkey, // admin replaced by pending_admin on accept_admin
+    pub approval_threshold: u8, // number of admin approvals required to execute a config proposal
+    pub min_update_delay: u64, // seconds a config change must be public before it applies
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl AdminRegistry {
+    pub fn space() -> usize {
+        std::mem::size_of::<AdminRegistry>() + 8 + MAX_ADMINS_LEN * 32
+    }
+
+    // migrates the compile-time ADMINS list
+    pub fn init(&mut self, bump: u8, created_at: u64) {
+        self.bump = bump;
+        self.admins = get_bootstrap_admins();
+        self.clear_pending_admin();
+        self.approval_threshold = 1;
+        self.created_at = created_at;
+    }
+
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        self.admins.contains(key)
+    }
+
+    pub fn get_admin_index(&self, key: &Pubkey) -> Option<usize> {
+        self.admins.iter().position(|admin| admin == key)
+    }
+
+    // approvals of removed admins don't count
+    pub fn num_valid_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter()
+            .filter(|approval| self.is_admin(approval))
+            .count()
+    }
+
+    // Proposals can be executed once min_update_delay has passed and expire PROPOSAL_LIFETIME later.
+    pub fn is_proposal_executable(&self, created_at: u64, current_time: u64) -> Result<bool> {
+        Ok(current_time >= created_at.checked_add(self.min_update_delay).ok_or(ContractError::MathOverflow)?)
+    }
+
+    pub fn is_proposal_expired(&self, created_at: u64, current_time: u64) -> bool {
+        created_at
+            .checked_add(self.min_update_delay)
+            .and_then(|executable_at| executable_at.checked_add(PROPOSAL_LIFETIME))
+            .map_or(false, |expires_at| current_time >= expires_at)
+    }
+
+    pub fn add_admin(&mut self, admin: Pubkey) -> Result<()> {
+        require!(!self.is_admin(&admin), ContractError::AdminAlreadyExists);
+        require!(self.admins.len() < MAX_ADMINS_LEN, ContractError::ExceedMaxNumAdmins);
+
+        self.admins.push(admin);
+
+        Ok(())
+    }
+
+    pub fn remove_admin(&mut self, admin: &Pubkey) -> Result<()> {
+        let admin_index = self.get_admin_index(admin).ok_or(ContractError::AdminNotFound)?;
+        require!(self.admins.len() > 1, ContractError::CannotRemoveLastAdmin);
+        require!(self.admins.len() > self.approval_threshold as usize, ContractError::InvalidApprovalThreshold);
+
+        self.admins.remove(admin_index);
+
+        if self.proposed_by == *admin { // a handover from a removed admin can't be accepted anymore
+            self.clear_pending_admin();
+        }
+
+        Ok(())
+    }
+
+    pub fn set_approval_threshold(&mut self, approval_threshold: u8) -> Result<()> {
+        require!(
+            approval_threshold > 0 && approval_threshold as usize <= self.admins.len(),
+            ContractError::InvalidApprovalThreshold
+        );
+
+        self.approval_threshold = approval_threshold;
+
+        Ok(())
+    }
+
+    pub fn is_proposal_required(&self) -> bool {
+        self.approval_threshold > 1
+    }
+
+    pub fn is_timelock_required(&self) -> bool {
+        self.min_update_delay > 0
+    }
+
+    // the pending slot is shared, an admin can only overwrite its own handover
+    pub fn propose_admin(&mut self, proposer: Pubkey, new_admin: Pubkey) -> Result<()> {
+        require!(new_admin != Pubkey::default(), ContractError::InvalidPendingAdmin);
+        require!(!self.is_admin(&new_admin), ContractError::AdminAlreadyExists);
+        require!(!self.has_pending_admin() || self.proposed_by == proposer, ContractError::PendingAdminExists);
+
+        self.pending_admin = new_admin;
+        self.proposed_by = proposer;
+
+        Ok(())
+    }
+
+    pub fn accept_admin(&mut self) -> Result<()> {
+        require!(self.has_pending_admin(), ContractError::InvalidPendingAdmin);
+        require!(self.get_admin_index(&self.pending_admin).is_none(), ContractError::AdminAlreadyExists); // added by add_admin since the proposal
+
+        let admin_index = self.get_admin_index(&self.proposed_by).ok_or(ContractError::AdminNotFound)?;
+
+        self.admins[admin_index] = self.pending_admin;
+        self.clear_pending_admin();
+
+        Ok(())
+    }
+
+    pub fn has_pending_admin(&self) -> bool {
+        self.pending_admin != Pubkey::default()
+    }
+
+    pub fn clear_pending_admin(&mut self) {
+        self.pending_admin = Pubkey::default();
+        self.proposed_by = Pubkey::default();
+    }
+}
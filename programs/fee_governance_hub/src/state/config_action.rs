@@ -0,0 +1,114 @@
+use {
+    crate::{constant::*, processor::UpdateConfigIx, state::*},
+    anchor_lang::prelude::*
+};
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfigAction { // changes of one config that need approval_threshold admins once it is above 1
+    Update(UpdateConfigIx),
+    SetActive { is_active: bool, inactive_fee_policy: InactiveFeePolicy },
+    AddFeeSchedule(FeeSchedule),
+    RemoveFeeSchedule { start_ts: u64 },
+    CreateFeeWaiver { payer: Pubkey, discount_percent: u64, expires_at: u64 },
+    UpdateFeeWaiver { payer: Pubkey, discount_percent: u64, expires_at: u64 },
+    RemoveFeeWaiver { payer: Pubkey },
+    Close, // rent goes to the admin executing it
+}
+
+impl ConfigAction {
+    // serialized bytes beyond size_of::<ConfigAction>()
+    pub fn extra_space(&self) -> usize {
+        match self {
+            ConfigAction::Update(payload) => payload.fee_wallets.len() * std::mem::size_of::<FeeWallet>() + MAX_FEE_INSTRUCTION_NAME_LEN,
+            _ => 0,
+        }
+    }
+
+    // config account size once this action is applied
+    pub fn config_space(&self, config: &Config) -> usize {
+        match self {
+            ConfigAction::Update(payload) => payload.space(),
+            _ => Config::space(config.fee_wallets.len()), // configs created before fee schedules have no room for them
+        }
+    }
+
+    // Fee waiver actions are only validated here and Close changes nothing, the caller applies them to the accounts
+    pub fn apply(&self, config: &mut Config, current_time: u64) -> Result<()> {
+        match self {
+            ConfigAction::Update(payload) => payload.apply(config),
+            ConfigAction::SetActive { is_active, inactive_fee_policy } => {
+                config.set_active(*is_active, *inactive_fee_policy);
+                Ok(())
+            }
+            ConfigAction::AddFeeSchedule(fee_schedule) => config.add_fee_schedule(fee_schedule.clone(), current_time),
+            ConfigAction::RemoveFeeSchedule { start_ts } => config.remove_fee_schedule(*start_ts),
+            ConfigAction::CreateFeeWaiver { discount_percent, expires_at, .. }
+                | ConfigAction::UpdateFeeWaiver { discount_percent, expires_at, .. } => {
+                FeeWaiver::default().set_discount(*discount_percent, *expires_at)
+            }
+            ConfigAction::RemoveFeeWaiver { .. } | ConfigAction::Close => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::error::ContractError};
+
+    #[test]
+    fn apply_validates_actions_against_config() {
+        let mut config = Config { version: CONFIG_VERSION, ..Default::default() };
+        let fee_schedule = FeeSchedule { start_ts: 10, end_ts: 20, fee_override: FeeOverride::Percent(PERCENT_DENOMINATOR / 2) };
+
+        ConfigAction::AddFeeSchedule(fee_schedule.clone()).apply(&mut config, 0).unwrap();
+        assert_eq!(config.fee_schedules, vec![fee_schedule.clone()]);
+        assert_eq!(
+            ConfigAction::AddFeeSchedule(fee_schedule).apply(&mut config, 0).unwrap_err(),
+            ContractError::FeeScheduleOverlap.into()
+        );
+
+        ConfigAction::SetActive { is_active: false, inactive_fee_policy: InactiveFeePolicy::Reject }.apply(&mut config, 0).unwrap();
+        assert!(!config.is_active());
+        assert_eq!(config.inactive_fee_policy, InactiveFeePolicy::Reject);
+
+        ConfigAction::RemoveFeeSchedule { start_ts: 10 }.apply(&mut config, 0).unwrap();
+        assert!(config.fee_schedules.is_empty());
+        assert_eq!(
+            ConfigAction::RemoveFeeSchedule { start_ts: 10 }.apply(&mut config, 0).unwrap_err(),
+            ContractError::FeeScheduleNotFound.into()
+        );
+
+        let fee_waiver = ConfigAction::CreateFeeWaiver { payer: Pubkey::new_unique(), discount_percent: PERCENT_DENOMINATOR + 1, expires_at: 0 };
+        assert_eq!(fee_waiver.apply(&mut config, 0).unwrap_err(), ContractError::InvalidDiscountPercent.into());
+    }
+
+    #[test]
+    fn proposal_space_fits_action() {
+        let actions = [
+            ConfigAction::Update(UpdateConfigIx {
+                fee_wallets: vec![FeeWallet::default(); MAX_FEE_WALLETS_LEN],
+                fee_instruction_name: "a".repeat(MAX_FEE_INSTRUCTION_NAME_LEN),
+                ..Default::default()
+            }),
+            ConfigAction::AddFeeSchedule(FeeSchedule { start_ts: 0, end_ts: 1, fee_override: FeeOverride::Amount(0) }),
+            ConfigAction::UpdateFeeWaiver { payer: Pubkey::new_unique(), discount_percent: 0, expires_at: 0 },
+            ConfigAction::Close,
+        ];
+
+        for action in actions {
+            let proposal = ConfigProposal {
+                bump: 255,
+                proposal_id: 0,
+                config: Pubkey::new_unique(),
+                proposer: Pubkey::new_unique(),
+                action: action.clone(),
+                approvals: vec![Pubkey::new_unique(); MAX_ADMINS_LEN],
+                created_at: 0,
+                reserved: [0; 2],
+            };
+            let mut data = Vec::new();
+            proposal.try_serialize(&mut data).unwrap();
+            assert!(data.len() <= ConfigProposal::space(&action));
+        }
+    }
+}
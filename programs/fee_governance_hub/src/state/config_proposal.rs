@@ -0,0 +1,31 @@
+use {
+    crate::{constant::*, state::{AdminRegistry, ConfigAction}},
+    anchor_lang::prelude::*
+};
+
+#[account]
+pub struct ConfigProposal { // Pending ConfigAction awaiting admin approvals.
+    pub bump: u8,
+    pub proposal_id: u64,
+    pub config: Pubkey, // config the action is applied to
+    pub proposer: Pubkey, // receives the rent back on execute
+    pub action: ConfigAction,
+    pub approvals: Vec<Pubkey>, // Maximum MAX_ADMINS_LEN
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl ConfigProposal {
+    pub fn space(action: &ConfigAction) -> usize {
+        std::mem::size_of::<ConfigProposal>() + 8 + action.extra_space() + MAX_ADMINS_LEN * 32
+    }
+
+    pub fn has_approved(&self, admin: &Pubkey) -> bool {
+        self.approvals.contains(admin)
+    }
+
+    pub fn num_valid_approvals(&self, admin_registry: &AdminRegistry) -> usize {
+        admin_registry.num_valid_approvals(&self.approvals)
+    }
+}
@@ -0,0 +1,23 @@
+use {
+    crate::state::FeeSchedule,
+    anchor_lang::prelude::*,
+};
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default)]
+pub struct FeeTransfer {
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default)]
+pub struct FeeQuote { // What transfer_fees charges. Returned by quote_fees.
+    pub fee_mint: Pubkey, // Pubkey::default() for SOL
+    pub base_amount: u64,
+    pub total_fee_amount: u64, // after the fee schedule and discount
+    pub discount_percent: u64, // from the payer's FeeWaiver, 0 if none
+    pub fee_schedule: Option<FeeSchedule>, // active promotional fee, if any
+    pub platform_fee_amount: u64, // to the global fee wallets when the config has a platform share
+    pub fee_wallets: Vec<FeeTransfer>, // in remaining accounts order, global fee wallets of the platform share first
+    pub treasury: Option<FeeTransfer>, // Treasury remainder policy only, passed after the fee wallets
+    pub is_waived: bool, // config inactive with the Waive policy, nothing is charged
+}
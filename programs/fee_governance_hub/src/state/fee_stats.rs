@@ -0,0 +1,62 @@
+use {
+    crate::{constant::*, state::FeeQuote, utils::*},
+    anchor_lang::prelude::*
+};
+
+#[repr(C)]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default)]
+pub struct WalletFeeTotal {
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[account]
+#[derive(Default)]
+pub struct FeeStats { // Cumulative fees charged through one config. Updated by transfer_fees when passed.
+    pub bump: u8,
+    pub config: Pubkey,
+    pub total_calls: u64, // waived calls included
+    pub total_waived_calls: u64,
+    pub total_fee_amount: u64, // in lamports or fee_mint units, whatever the config charged at the time
+    pub wallet_totals: Vec<WalletFeeTotal>,
+    pub untracked_fee_amount: u64, // received by wallets beyond MAX_FEE_STATS_WALLETS_LEN
+    pub last_charged_at: u64,
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl FeeStats {
+    pub fn space() -> usize {
+        std::mem::size_of::<FeeStats>() + 8 + MAX_FEE_STATS_WALLETS_LEN * std::mem::size_of::<WalletFeeTotal>()
+    }
+
+    pub fn record(&mut self, fee_quote: &FeeQuote, current_time: u64) -> Result<()> {
+        self.total_calls = self.total_calls.safe_add(1)?;
+        self.last_charged_at = current_time;
+
+        if fee_quote.is_waived {
+            self.total_waived_calls = self.total_waived_calls.safe_add(1)?;
+            return Ok(());
+        }
+
+        self.total_fee_amount = self.total_fee_amount.safe_add(fee_quote.total_fee_amount)?;
+
+        for fee_transfer in fee_quote.fee_wallets.iter().chain(fee_quote.treasury.iter()) {
+            let num_wallet_totals = self.wallet_totals.len();
+            match self.wallet_totals.iter_mut().find(|wallet_total| wallet_total.wallet == fee_transfer.wallet) {
+                Some(wallet_total) => {
+                    wallet_total.amount = wallet_total.amount.safe_add(fee_transfer.amount)?;
+                }
+                None if num_wallet_totals < MAX_FEE_STATS_WALLETS_LEN => {
+                    self.wallet_totals.push(WalletFeeTotal { wallet: fee_transfer.wallet, amount: fee_transfer.amount });
+                }
+                None => {
+                    self.untracked_fee_amount = self.untracked_fee_amount.safe_add(fee_transfer.amount)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use {
+    crate::{constant::*, error::ContractError},
+    anchor_lang::prelude::*
+};
+
+#[repr(C)]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default)]
+pub struct FeeBalance {
+    pub wallet: Pubkey,
+    pub amount: u64, // accrued and not yet claimed
+}
+
+#[account]
+#[derive(Default)]
+pub struct FeeVault { // Holds the fees of an accrual mode config until its fee wallets claim them
+    pub bump: u8,
+    pub config: Pubkey,
+    pub fee_mint: Pubkey, // Pubkey::default() for SOL, lamports are held by this account
+    pub balances: Vec<FeeBalance>,
+    pub total_accrued: u64,
+    pub total_claimed: u64,
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl FeeVault {
+    pub fn space() -> usize {
+        std::mem::size_of::<FeeVault>() + 8 + MAX_FEE_VAULT_BALANCES_LEN * std::mem::size_of::<FeeBalance>()
+    }
+
+    pub fn is_token_vault(&self) -> bool {
+        self.fee_mint != Pubkey::default()
+    }
+
+    pub fn credit(&mut self, wallet: Pubkey, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        match self.balances.iter_mut().find(|balance| balance.wallet == wallet) {
+            Some(balance) => {
+                balance.amount = balance.amount.checked_add(amount).ok_or(ContractError::MathOverflow)?;
+            }
+            None => { // a full vault fails transfer_fees until fee wallets claim
+                require!(self.balances.len() < MAX_FEE_VAULT_BALANCES_LEN, ContractError::ExceedMaxNumFeeBalances);
+                self.balances.push(FeeBalance { wallet, amount });
+            }
+        }
+
+        self.total_accrued = self.total_accrued.checked_add(amount).ok_or(ContractError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    // removes and returns the whole balance of wallet
+    pub fn claim(&mut self, wallet: Pubkey) -> Result<u64> {
+        let index = self.balances.iter().position(|balance| balance.wallet == wallet).ok_or(ContractError::NothingToClaim)?;
+        let amount = self.balances.remove(index).amount;
+
+        self.total_claimed = self.total_claimed.checked_add(amount).ok_or(ContractError::MathOverflow)?;
+
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_vault_refuses_new_wallets() {
+        let mut fee_vault = FeeVault::default();
+        let wallets: Vec<Pubkey> = (0..MAX_FEE_VAULT_BALANCES_LEN).map(|_| Pubkey::new_unique()).collect();
+        for wallet in wallets.iter() {
+            fee_vault.credit(*wallet, 10).unwrap();
+        }
+
+        assert_eq!(fee_vault.credit(Pubkey::new_unique(), 10).unwrap_err(), ContractError::ExceedMaxNumFeeBalances.into());
+        fee_vault.credit(wallets[0], 10).unwrap();
+        assert_eq!(fee_vault.total_accrued, (MAX_FEE_VAULT_BALANCES_LEN as u64 + 1) * 10);
+
+        // claiming frees a balance
+        assert_eq!(fee_vault.claim(wallets[0]).unwrap(), 20);
+        fee_vault.credit(Pubkey::new_unique(), 10).unwrap();
+    }
+}
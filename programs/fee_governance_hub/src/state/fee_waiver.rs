@@ -0,0 +1,42 @@
+use {
+    crate::{constant::*, error::ContractError},
+    anchor_lang::prelude::*
+};
+
+#[account]
+#[derive(Default)]
+pub struct FeeWaiver { // Discount for one payer on one config. Passed as the last remaining account of transfer_fees.
+    pub bump: u8,
+    pub config: Pubkey,
+    pub payer: Pubkey,
+    pub discount_percent: u64, // out of PERCENT_DENOMINATOR, PERCENT_DENOMINATOR waives the whole fee
+    pub expires_at: u64, // 0 for no expiry
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl FeeWaiver {
+    pub fn set_discount(&mut self, discount_percent: u64, expires_at: u64) -> Result<()> {
+        require!(discount_percent <= PERCENT_DENOMINATOR, ContractError::InvalidDiscountPercent);
+
+        self.discount_percent = discount_percent;
+        self.expires_at = expires_at;
+
+        Ok(())
+    }
+
+    pub fn is_active(&self, current_time: u64) -> bool {
+        self.expires_at == 0 || current_time < self.expires_at
+    }
+
+    pub fn apply_discount(&self, fee_amount: u64) -> Result<u64> {
+        let discounted_amount = (fee_amount as u128)
+            .checked_mul(PERCENT_DENOMINATOR.checked_sub(self.discount_percent).ok_or(ContractError::MathOverflow)? as u128)
+            .ok_or(ContractError::MathOverflow)?
+            .checked_div(PERCENT_DENOMINATOR as u128)
+            .ok_or(ContractError::MathOverflow)?;
+
+        Ok(u64::try_from(discounted_amount).map_err(|_| ContractError::MathOverflow)?)
+    }
+}
@@ -0,0 +1,31 @@
+use {
+    crate::{state::FeeWallet, utils::*},
+    anchor_lang::prelude::*
+};
+
+#[account]
+#[derive(Default, PartialEq, Debug)]
+pub struct GlobalConfig { // Singleton. Fee wallets used by every config with is_using_global_fee_wallets.
+    pub bump: u8,
+    pub fee_wallets: Vec<FeeWallet>,
+
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl GlobalConfig {
+    pub fn space(num_fee_wallets: usize) -> usize {
+        std::mem::size_of::<GlobalConfig>() + 8 + num_fee_wallets * std::mem::size_of::<FeeWallet>()
+    }
+
+    // returns the old fee wallets
+    pub fn update(&mut self, fee_wallets: &[FeeWallet], updated_at: u64) -> Result<Vec<FeeWallet>> {
+        validate_fee_wallets(fee_wallets)?;
+
+        let old_fee_wallets = std::mem::replace(&mut self.fee_wallets, compact_fee_wallets(fee_wallets));
+        self.updated_at = updated_at;
+
+        Ok(old_fee_wallets)
+    }
+}
@@ -1,2 +1,35 @@
 pub mod config;
 pub use config::*;
+
+pub mod global_config;
+pub use global_config::*;
+
+pub mod admin_registry;
+pub use admin_registry::*;
+
+pub mod config_action;
+pub use config_action::*;
+
+pub mod config_proposal;
+pub use config_proposal::*;
+
+pub mod admin_proposal;
+pub use admin_proposal::*;
+
+pub mod pending_config_update;
+pub use pending_config_update::*;
+
+pub mod fee_waiver;
+pub use fee_waiver::*;
+
+pub mod fee_quote;
+pub use fee_quote::*;
+
+pub mod fee_vault;
+pub use fee_vault::*;
+
+pub mod fee_stats;
+pub use fee_stats::*;
+
+pub mod program_manager;
+pub use program_manager::*;
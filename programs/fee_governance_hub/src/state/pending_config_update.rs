@@ -0,0 +1,22 @@
+use {
+    crate::state::ConfigAction,
+    anchor_lang::prelude::*
+};
+
+#[account]
+pub struct PendingConfigUpdate { // Scheduled ConfigAction. One per config.
+    pub bump: u8,
+    pub config: Pubkey, // config the action is applied to
+    pub scheduled_by: Pubkey, // admin or program manager, receives the rent back on apply/cancel
+    pub action: ConfigAction,
+    pub effective_at: u64, // action can be applied from this timestamp
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
+
+impl PendingConfigUpdate {
+    pub fn space(action: &ConfigAction) -> usize {
+        std::mem::size_of::<PendingConfigUpdate>() + 8 + action.extra_space()
+    }
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct ProgramManager { // Lets manager update the configs of program that don't use the global fee wallets
+    pub bump: u8,
+    pub program: Pubkey,
+    pub manager: Pubkey,
+    pub granted_by: Pubkey, // admin
+
+    pub created_at: u64,
+    pub reserved: [u128; 2],
+}
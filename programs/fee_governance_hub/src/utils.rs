@@ -1,11 +1,262 @@
-use anchor_lang::prelude::*;
-use crate::constant::*;
+use anchor_lang::{prelude::*, system_program};
+use crate::{constant::*, error::ContractError, state::{FeeWallet, RemainderPolicy}};
 use std::str::FromStr;
 
-pub fn is_admin(key: &Pubkey)->bool {
+pub fn get_bootstrap_admins() -> Vec<Pubkey> {
     let mut authorities = vec![];
     for admin in ADMINS {
         authorities.push(Pubkey::from_str(admin).unwrap())
     }
-    return authorities.contains(key)
+    authorities
+}
+
+pub fn is_bootstrap_admin(key: &Pubkey)->bool {
+    get_bootstrap_admins().contains(key)
+}
+
+// Fee wallets must split exactly PERCENT_DENOMINATOR. Filler entries are Pubkey::default() with 0 percent.
+pub fn validate_fee_wallets(fee_wallets: &[FeeWallet]) -> Result<()> {
+    require!(fee_wallets.len() <= MAX_FEE_WALLETS_LEN, ContractError::ExceedMaxNumFeeWallets);
+
+    let mut total_percent: u64 = 0;
+    for (index, fee_wallet) in fee_wallets.iter().enumerate() {
+        require!(fee_wallet.fee_percent <= PERCENT_DENOMINATOR, ContractError::FeePercentTooHigh);
+
+        if fee_wallet.address == Pubkey::default() {
+            require!(fee_wallet.fee_percent == 0, ContractError::InvalidFeeWallet);
+            continue;
+        }
+
+        require!(fee_wallet.fee_percent > 0, ContractError::ZeroPercentFeeWallet);
+        require!(
+            !fee_wallets[..index].iter().any(|other| other.address == fee_wallet.address),
+            ContractError::DuplicateFeeWallet
+        );
+
+        total_percent = total_percent.checked_add(fee_wallet.fee_percent).ok_or(ContractError::MathOverflow)?;
+    }
+
+    require!(total_percent == PERCENT_DENOMINATOR, ContractError::InvalidFeePercentSum);
+
+    Ok(())
+}
+
+// Per-wallet share of total_fee_amount plus the treasury amount. The rounding remainder is assigned by remainder_policy so the total is always total_fee_amount.
+pub fn split_fee_amount(fee_wallets: &[FeeWallet], total_fee_amount: u64, remainder_policy: RemainderPolicy) -> Result<(Vec<u64>, u64)> {
+    let mut fee_amounts = Vec::with_capacity(fee_wallets.len());
+    let mut distributed_amount: u64 = 0;
+    for fee_wallet in fee_wallets {
+        let fee_amount = (fee_wallet.fee_percent as u128)
+            .checked_mul(total_fee_amount as u128)
+            .ok_or(ContractError::MathOverflow)?
+            .checked_div(PERCENT_DENOMINATOR as u128)
+            .ok_or(ContractError::MathOverflow)?;
+        let fee_amount = u64::try_from(fee_amount).map_err(|_| ContractError::MathOverflow)?;
+
+        distributed_amount = distributed_amount.checked_add(fee_amount).ok_or(ContractError::MathOverflow)?;
+        fee_amounts.push(fee_amount);
+    }
+
+    let remainder = total_fee_amount.checked_sub(distributed_amount).ok_or(ContractError::MathOverflow)?;
+    let remainder_index = match remainder_policy {
+        RemainderPolicy::FirstWallet => fee_wallets.iter().position(|fee_wallet| fee_wallet.fee_percent > 0),
+        RemainderPolicy::LargestShare => fee_wallets.iter()
+            .enumerate()
+            .filter(|(_, fee_wallet)| fee_wallet.fee_percent > 0)
+            .max_by(|(a_index, a), (b_index, b)| a.fee_percent.cmp(&b.fee_percent).then(b_index.cmp(a_index)))
+            .map(|(index, _)| index),
+        RemainderPolicy::Treasury => return Ok((fee_amounts, remainder)),
+    };
+
+    if let Some(remainder_index) = remainder_index {
+        fee_amounts[remainder_index] = fee_amounts[remainder_index].checked_add(remainder).ok_or(ContractError::MathOverflow)?;
+    }
+
+    Ok((fee_amounts, 0))
+}
+
+// Drops filler entries, so only real recipients are stored and passed as remaining accounts.
+pub fn compact_fee_wallets(fee_wallets: &[FeeWallet]) -> Vec<FeeWallet> {
+    fee_wallets.iter()
+        .filter(|fee_wallet| fee_wallet.address != Pubkey::default() || fee_wallet.fee_percent > 0)
+        .cloned()
+        .collect()
+}
+
+// Creates a PDA account owned by this program, like Anchor's init. An account that was already funded is topped up instead.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_amount = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(system_program.clone(), system_program::CreateAccount {
+                from: payer.clone(),
+                to: account.clone(),
+            }, &[signer_seeds]),
+            rent_amount,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    if rent_amount > current_lamports {
+        system_program::transfer(
+            CpiContext::new(system_program.clone(), system_program::Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            }),
+            rent_amount - current_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(system_program.clone(), system_program::Allocate {
+            account_to_allocate: account.clone(),
+        }, &[signer_seeds]),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(system_program.clone(), system_program::Assign {
+            account_to_assign: account.clone(),
+        }, &[signer_seeds]),
+        &crate::ID,
+    )
+}
+
+// Reallocs an account of this program to space, like Anchor's realloc. payer funds the extra rent and gets the excess back when it shrinks.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    if account.data_len() == space {
+        return Ok(());
+    }
+
+    let rent_amount = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if rent_amount > current_lamports {
+        system_program::transfer(
+            CpiContext::new(system_program.clone(), system_program::Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            }),
+            rent_amount - current_lamports,
+        )?;
+    } else if space < account.data_len() {
+        let refund_amount = current_lamports - rent_amount;
+        **account.try_borrow_mut_lamports()? -= refund_amount;
+        **payer.try_borrow_mut_lamports()? += refund_amount;
+    }
+
+    account.realloc(space, false)?;
+
+    Ok(())
+}
+
+// Closes an account of this program that isn't loaded as an Account, like Anchor's close.
+pub fn close_account<'info>(account: &AccountInfo<'info>, rent_receiver: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **rent_receiver.try_borrow_mut_lamports()? = rent_receiver.lamports().checked_add(lamports).ok_or(ContractError::MathOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+
+    Ok(())
+}
+
+pub trait SafeCalc<T> {
+    fn safe_add(&self, num: T) -> Result<T>;
+    fn safe_sub(&self, num: T) -> Result<T>;
+    fn safe_mul(&self, num: T) -> Result<T>;
+    fn safe_div(&self, num: T) -> Result<T>;
+}
+impl SafeCalc<u64> for u64 {
+    fn safe_add(&self, num: u64) -> Result<u64> {
+        let result = self.checked_add(num);
+        if result.is_none() {
+            return Err(error!(ContractError::MathOverflow));
+        }
+        Ok(result.unwrap())
+    }
+    fn safe_sub(&self, num: u64) -> Result<u64> {
+        let result = self.checked_sub(num);
+        if result.is_none() {
+            return Err(error!(ContractError::MathOverflow));
+        }
+        Ok(result.unwrap())
+    }
+    fn safe_mul(&self, num: u64) -> Result<u64> {
+        let result = self.checked_mul(num);
+        if result.is_none() {
+            return Err(error!(ContractError::MathOverflow));
+        }
+        Ok(result.unwrap())
+    }
+    fn safe_div(&self, num: u64) -> Result<u64> {
+        let result = self.checked_div(num);
+        if result.is_none() {
+            return Err(error!(ContractError::MathOverflow));
+        }
+        Ok(result.unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, proptest::prelude::*};
+
+    // fee wallets with percents summing to PERCENT_DENOMINATOR, cut at random points. Some may get 0 percent.
+    fn fee_wallets_strategy() -> impl Strategy<Value = Vec<FeeWallet>> {
+        prop::collection::vec(0..=PERCENT_DENOMINATOR, 0..MAX_FEE_WALLETS_LEN).prop_map(|mut cuts| {
+            cuts.push(0);
+            cuts.push(PERCENT_DENOMINATOR);
+            cuts.sort_unstable();
+
+            cuts.windows(2)
+                .map(|cut| FeeWallet { address: Pubkey::new_unique(), fee_percent: cut[1] - cut[0] })
+                .collect()
+        })
+    }
+
+    fn remainder_policy_strategy() -> impl Strategy<Value = RemainderPolicy> {
+        prop_oneof![
+            Just(RemainderPolicy::FirstWallet),
+            Just(RemainderPolicy::LargestShare),
+            Just(RemainderPolicy::Treasury),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn split_fee_amount_distributes_total(
+            fee_wallets in fee_wallets_strategy(),
+            total_fee_amount in any::<u64>(),
+            remainder_policy in remainder_policy_strategy(),
+        ) {
+            let (fee_amounts, treasury_amount) = split_fee_amount(&fee_wallets, total_fee_amount, remainder_policy).unwrap();
+
+            prop_assert_eq!(fee_amounts.len(), fee_wallets.len());
+            let distributed_amount = fee_amounts.iter().map(|fee_amount| *fee_amount as u128).sum::<u128>();
+            prop_assert_eq!(distributed_amount + treasury_amount as u128, total_fee_amount as u128);
+
+            if remainder_policy != RemainderPolicy::Treasury {
+                prop_assert_eq!(treasury_amount, 0);
+            }
+            for (fee_wallet, fee_amount) in fee_wallets.iter().zip(fee_amounts.iter()) {
+                if fee_wallet.fee_percent == 0 {
+                    prop_assert_eq!(*fee_amount, 0);
+                }
+            }
+        }
+    }
 }
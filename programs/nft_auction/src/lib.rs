@@ -1,3 +1,5 @@
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 declare_id!("Ad1y5v7dsQyiJF1cztiy9nUS2skKGC3apf8ngcacnQS8");
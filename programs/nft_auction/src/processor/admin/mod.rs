@@ -1,5 +1,6 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod process_add_creator;
-#[allow(ambiguous_glob_reexports)]
 pub use process_add_creator::*;
 
 pub mod process_edit_creator;
@@ -1,12 +1,12 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod process_create_auction;
-#[allow(ambiguous_glob_reexports)]
 pub use process_create_auction::*;
 
 pub mod process_create_auction_v2;
 pub use process_create_auction_v2::*;
 
 pub mod process_create_auction_v3;
-#[allow(ambiguous_glob_reexports)]
 pub use process_create_auction_v3::*;
 
 pub mod process_init_auction_vault;
@@ -114,11 +114,11 @@ pub fn handler<'info>(
             system_program: &system_program,
         },
         mpl_bubblegum::instructions::TransferInstructionArgs {
-            root: root,
-            data_hash: data_hash,
-            creator_hash: creator_hash,
-            nonce: nonce,
-            index: index,
+            root,
+            data_hash,
+            creator_hash,
+            nonce,
+            index,
         },
     );
 
@@ -99,7 +99,7 @@ pub fn handler(
         asset: &ctx.accounts.nft_mint.to_account_info(),
         collection: ctx.accounts.collection.as_ref(),
         payer: &ctx.accounts.creator.to_account_info(),
-        authority: Some(&ctx.accounts.creator.as_ref()),
+        authority: Some(ctx.accounts.creator.as_ref()),
         new_owner: &auction.to_account_info(),
         system_program: Some(ctx.accounts.system_program.as_ref()),
         log_wrapper: ctx.accounts.log_wrapper.as_ref(),
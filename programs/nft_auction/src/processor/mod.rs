@@ -1,9 +1,9 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod admin;
-#[allow(ambiguous_glob_reexports)]
 pub use admin::*;
 
 pub mod creator;
-#[allow(ambiguous_glob_reexports)]
 pub use creator::*;
 
 pub mod user;
@@ -1,5 +1,6 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod process_place_bid;
-#[allow(ambiguous_glob_reexports)]
 pub use process_place_bid::*;
 
 pub mod process_place_bid_2022;
@@ -18,7 +19,6 @@ pub mod process_claim_nft_v2_2022;
 pub use process_claim_nft_v2_2022::*;
 
 pub mod process_claim_nft_v3;
-#[allow(ambiguous_glob_reexports)]
 pub use process_claim_nft_v3::*;
 
 pub mod process_claim_nft_v3_2022;
@@ -148,7 +148,7 @@ pub fn handler(ctx: Context<ClaimNftV22022>) -> Result<()> {
         collection: ctx.accounts.collection.as_ref(),
         payer: &ctx.accounts.authority.to_account_info(),
         authority: Some(&ctx.accounts.auction.to_account_info()),
-        new_owner: &ctx.accounts.claimer.as_ref(),
+        new_owner: ctx.accounts.claimer.as_ref(),
         system_program: Some(ctx.accounts.system_program.as_ref()),
         log_wrapper: ctx.accounts.log_wrapper.as_ref(),
         __program: &ctx.accounts.mpl_core,
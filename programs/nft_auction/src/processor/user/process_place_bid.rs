@@ -83,14 +83,12 @@ pub fn handler(ctx: Context<PlaceBid>, bid_amount: u64) -> Result<()> {
     // Validate bid amount
     let minimum_bid = if ctx.accounts.auction.current_bid == 0 {
         ctx.accounts.auction.start_price
+    } else if ctx.accounts.auction.tick_option == TICK_OPTION_PERCENTAGE {
+        ctx.accounts.auction.current_bid + (ctx.accounts.auction.current_bid / 100) * ctx.accounts.auction.tick_amount
+    } else if ctx.accounts.auction.tick_option == TICK_OPTION_FLAT {
+        ctx.accounts.auction.current_bid + ctx.accounts.auction.tick_amount
     } else {
-        if ctx.accounts.auction.tick_option == TICK_OPTION_PERCENTAGE {
-            ctx.accounts.auction.current_bid + (ctx.accounts.auction.current_bid / 100) * ctx.accounts.auction.tick_amount
-        } else if ctx.accounts.auction.tick_option == TICK_OPTION_FLAT {
-            ctx.accounts.auction.current_bid + ctx.accounts.auction.tick_amount
-        } else {
-            ctx.accounts.auction.current_bid + (ctx.accounts.auction.current_bid / 100)
-        }
+        ctx.accounts.auction.current_bid + (ctx.accounts.auction.current_bid / 100)
     };
     require!(bid_amount >= minimum_bid, ContractError::BidTooLow);
 
@@ -27,11 +27,9 @@ pub struct Auction {
 
 impl Auction {
     pub fn is_native_accepted_mint(&self) -> bool {
-        let key_from_str = Pubkey::from_str("So11111111111111111111111111111111111111112");
-        if key_from_str.is_ok() {
-            return key_from_str.unwrap().eq(&self.accepted_mint);
-        } else {
-            return false;
+        match Pubkey::from_str("So11111111111111111111111111111111111111112") {
+            Ok(native_mint) => native_mint.eq(&self.accepted_mint),
+            Err(_) => false,
         }
     }
 }
\ No newline at end of file
@@ -13,10 +13,8 @@ pub fn calculate_fee(fee_type: u8, fee_amount: u64, amount: u64, decimals: u8) -
 }
 
 pub fn is_super_admin(key: &Pubkey) -> bool {
-    let key_from_str = Pubkey::from_str(SUPER_ADMIN);
-    if key_from_str.is_ok() {
-        return key_from_str.unwrap().eq(key);
-    } else {
-        return false;
+    match Pubkey::from_str(SUPER_ADMIN) {
+        Ok(super_admin) => super_admin.eq(key),
+        Err(_) => false,
     }
 }
\ No newline at end of file
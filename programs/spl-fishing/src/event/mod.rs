@@ -1,5 +1,6 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod flip;
-#[allow(ambiguous_glob_reexports)]
 pub use flip::*;
 
 pub mod game;
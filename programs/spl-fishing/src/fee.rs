@@ -0,0 +1,3 @@
+use anchor_lang::prelude::*;
+
+fee_governance_hub::fee_accounts!();
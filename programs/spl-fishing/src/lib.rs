@@ -14,6 +14,8 @@ pub mod state;
 pub mod utils;
 /// event
 pub mod event;
+/// fee_governance_hub accounts
+pub mod fee;
 
 use crate::processor::*;
 
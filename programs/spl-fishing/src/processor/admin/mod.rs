@@ -1,5 +1,6 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod process_create_game;
-#[allow(ambiguous_glob_reexports)]
 pub use process_create_game::*;
 
 pub mod process_edit_game;
@@ -1,13 +1,8 @@
 use {
-  crate::{constant::*, error::ContractError, event::*, state::*},
+  crate::{constant::*, error::ContractError, event::*, fee::*, state::*},
   anchor_lang::prelude::*,
   anchor_spl::token::{Mint, Token, TokenAccount},
-  fee_governance_hub::{
-    self,
-    cpi::{transfer_fees, accounts::TransferFeesCtx},
-    processor::TransferFeesIx,
-    state::Config as FeeConfig,
-  },
+  fee_governance_hub::processor::TransferFeesIx,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -48,16 +43,7 @@ pub struct CreateGameCtx<'info> {
   )]
   pub payment_token_vault: Box<Account<'info, TokenAccount>>,
 
-  #[account(
-    constraint = fee_config.program == this_program.key() @ ContractError::InvalidFeeConfig,
-  )]
-  pub fee_config: Box<Account<'info, FeeConfig>>,
-
-  /// CHECK: we read this key only
-  pub fee_governance_hub: UncheckedAccount<'info>,
-
-  /// CHECK: we read this key only
-  pub this_program: UncheckedAccount<'info>,
+  pub fee_accounts: FeeAccounts<'info>,
 
   pub token_program: Program<'info, Token>,
   pub system_program: Program<'info, System>,
@@ -67,19 +53,16 @@ pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CreateGameCtx<'info>>, ix:
   let game = &mut ctx.accounts.game;
 
   if ctx.accounts.colleague.is_none() {
-    transfer_fees(
-      CpiContext::new(
-        ctx.accounts.fee_governance_hub.to_account_info(),
-        TransferFeesCtx {
-          authority: ctx.accounts.authority.to_account_info(),
-          config: ctx.accounts.fee_config.to_account_info(),
-          target_program: ctx.accounts.this_program.to_account_info(),
-          system_program: ctx.accounts.system_program.to_account_info(),
-        }
-      ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+    ctx.accounts.fee_accounts.charge(
+      ctx.program_id,
+      ctx.accounts.authority.to_account_info(),
+      None,
+      ctx.accounts.system_program.to_account_info(),
+      ctx.remaining_accounts,
       TransferFeesIx {
         fee_instruction_index: CREATE_GAME_INSTRUCTION_INDEX as u64,
-      }
+        base_amount: ix.payment_token_unit_value,
+      },
     )?;
   } else if let Some(ref mut colleague) = ctx.accounts.colleague {
     require!(colleague.wallet == ctx.accounts.authority.key(), ContractError::InvalidColleague);
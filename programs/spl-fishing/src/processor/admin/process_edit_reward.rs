@@ -47,6 +47,7 @@ pub struct EditRewardCtx<'info> {
   pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::comparison_chain)]
 pub fn handler(ctx: Context<EditRewardCtx>, ix: EditRewardIx) -> Result<()> {
   let game = &mut ctx.accounts.game;
   let game_account_info = game.to_account_info();
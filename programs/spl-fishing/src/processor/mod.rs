@@ -1,5 +1,6 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod super_admin;
-#[allow(ambiguous_glob_reexports)]
 pub use super_admin::*;
 
 pub mod admin;
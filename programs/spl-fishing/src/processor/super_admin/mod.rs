@@ -1,5 +1,6 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod process_create_colleague;
-#[allow(ambiguous_glob_reexports)]
 pub use process_create_colleague::*;
 
 pub mod process_remove_colleague;
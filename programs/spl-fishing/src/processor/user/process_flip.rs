@@ -1,12 +1,10 @@
 use {
-    crate::{constant::*, state::*, utils::*, error::ContractError, event::flip::FlipEvent},
+    crate::{constant::*, state::*, utils::*, error::ContractError, event::flip::FlipEvent, fee::*},
     anchor_lang::prelude::*,
     anchor_spl::token::{Mint, Token, TokenAccount, Transfer},
     fee_governance_hub::{
-      self,
-      cpi::{transfer_fees, accounts::TransferFeesCtx},
+      integration::FeeTokenAccounts,
       processor::TransferFeesIx,
-      state::Config as FeeConfig,
     },
 };
 
@@ -39,16 +37,7 @@ pub struct FlipCtx<'info> {
   )]
   pub payment_token_vault: Box<Account<'info, TokenAccount>>,  
 
-  #[account(
-    constraint = fee_config.program == this_program.key() @ ContractError::InvalidFeeConfig,
-  )]
-  pub fee_config: Box<Account<'info, FeeConfig>>,
-
-  /// CHECK: we read this key only
-  pub fee_governance_hub: UncheckedAccount<'info>,
-
-  /// CHECK: we read this key only
-  pub this_program: UncheckedAccount<'info>,
+  pub fee_accounts: FeeAccounts<'info>,
 
   pub token_program: Program<'info, Token>,
   pub system_program: Program<'info, System>,
@@ -61,21 +50,20 @@ pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, FlipCtx<'info>>) -> Result
 
   require!(game.is_flip_available(ctx.accounts.authority.key()), ContractError::FlipNotAvailable);
 
-  require!(ctx.accounts.this_program.key().eq(ctx.program_id), ContractError::InvalidProgramId);
-
-  transfer_fees(
-    CpiContext::new(
-      ctx.accounts.fee_governance_hub.to_account_info(),
-      TransferFeesCtx {
-        authority: ctx.accounts.authority.to_account_info(),
-        config: ctx.accounts.fee_config.to_account_info(),
-        target_program: ctx.accounts.this_program.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-      }
-    ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+  ctx.accounts.fee_accounts.charge(
+    ctx.program_id,
+    ctx.accounts.authority.to_account_info(),
+    Some(FeeTokenAccounts {
+      fee_mint: ctx.accounts.payment_token_mint.to_account_info(),
+      payer_token_account: ctx.accounts.user_payment_token_vault.to_account_info(),
+      token_program: ctx.accounts.token_program.to_account_info(),
+    }),
+    ctx.accounts.system_program.to_account_info(),
+    ctx.remaining_accounts,
     TransferFeesIx {
       fee_instruction_index: FLIP_INSTRUCTION_INDEX as u64,
-    }
+      base_amount: game.payment_token_unit_value,
+    },
   )?;
 
   anchor_spl::token::transfer(
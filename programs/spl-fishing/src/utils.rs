@@ -6,11 +6,9 @@ use crate::{
 use std::str::FromStr;
 
 pub fn is_super_admin(key: &Pubkey) -> bool {
-    let key_from_str = Pubkey::from_str(SUPER_ADMIN);
-    if key_from_str.is_ok() {
-        return key_from_str.unwrap().eq(key);
-    } else {
-        return false;
+    match Pubkey::from_str(SUPER_ADMIN) {
+        Ok(super_admin) => super_admin.eq(key),
+        Err(_) => false,
     }
 }
 
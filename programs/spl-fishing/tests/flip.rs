@@ -0,0 +1,253 @@
+// flip charges its fee through fee_governance_hub, with a player who is neither an admin nor the game creator as payer
+use {
+  anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, instruction::Instruction, program_option::COption, program_pack::Pack},
+    system_program, AccountSerialize, InstructionData, ToAccountMetas,
+  },
+  anchor_spl::token::spl_token,
+  fee_governance_hub::{
+    client::{find_config_address, find_fee_caller_address},
+    constant::{CONFIG_VERSION, PERCENT_DENOMINATOR},
+    state::{Config, FeeWallet},
+  },
+  solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext},
+  solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction},
+  spl_fishing::{
+    constant::{FLIP_INSTRUCTION_INDEX, GAME_SEED, MAX_NUM_REVEAL_PENDING_PLAYERS, MAX_NUM_REWARD_TOKENS, VAULT_SEED},
+    state::{Game, RewardTokenInfo},
+  },
+};
+
+const FEE_AMOUNT: u64 = 1_000_000;
+const TOKEN_FEE_AMOUNT: u64 = 10; // in payment tokens
+const PAYMENT_TOKEN_UNIT_VALUE: u64 = 100;
+const FEE_WALLET_BALANCE: u64 = 1_000_000_000;
+
+// anchor's entry ties the accounts slice to the account lifetime, processor! expects them independent
+fn process_spl_fishing(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+  // SAFETY: only the lifetime parameters change. The slice and its AccountInfos live for the whole call, and entry
+  // returns before processor! drops them, so no reference outlives the accounts.
+  let accounts = unsafe { std::mem::transmute::<&[AccountInfo], &[AccountInfo]>(accounts) };
+  spl_fishing::entry(program_id, accounts, data)
+}
+
+fn process_fee_governance_hub(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+  // SAFETY: same as process_spl_fishing, entry doesn't keep the accounts past the call
+  let accounts = unsafe { std::mem::transmute::<&[AccountInfo], &[AccountInfo]>(accounts) };
+  fee_governance_hub::entry(program_id, accounts, data)
+}
+
+fn anchor_account<T: AccountSerialize>(owner: Pubkey, account: &T, space: usize) -> Account {
+  let mut data = Vec::with_capacity(space);
+  account.try_serialize(&mut data).unwrap();
+  data.resize(space, 0);
+
+  Account { lamports: Rent::default().minimum_balance(space), data, owner, executable: false, rent_epoch: 0 }
+}
+
+fn packed_account<T: Pack>(state: T) -> Account {
+  let mut data = vec![0; T::LEN];
+  T::pack(state, &mut data).unwrap();
+
+  Account { lamports: Rent::default().minimum_balance(T::LEN), data, owner: spl_token::ID, executable: false, rent_epoch: 0 }
+}
+
+fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+  packed_account(spl_token::state::Account {
+    mint,
+    owner,
+    amount,
+    state: spl_token::state::AccountState::Initialized,
+    ..Default::default()
+  })
+}
+
+struct Fixture {
+  context: ProgramTestContext,
+  player: Keypair,
+  game: Pubkey,
+  payment_token_mint: Pubkey,
+  user_payment_token_vault: Pubkey,
+  payment_token_vault: Pubkey,
+  fee_config: Pubkey,
+  fee_wallets: Vec<FeeWallet>,
+  fee_wallet_token_accounts: Vec<Pubkey>, // token fees only
+}
+
+// flip's fee is FEE_AMOUNT lamports, or TOKEN_FEE_AMOUNT payment tokens with is_token_fee
+async fn setup(is_token_fee: bool) -> Fixture {
+  let mut program_test = ProgramTest::new("spl_fishing", spl_fishing::ID, processor!(process_spl_fishing));
+  program_test.add_program("fee_governance_hub", fee_governance_hub::ID, processor!(process_fee_governance_hub));
+
+  let player = Keypair::new();
+  program_test.add_account(player.pubkey(), Account::new(FEE_WALLET_BALANCE, 0, &system_program::ID));
+
+  // game of another creator with one reward left to win
+  let game_authority = Pubkey::new_unique();
+  let (game, game_bump) = Pubkey::find_program_address(&[GAME_SEED, game_authority.as_ref(), 0u64.to_le_bytes().as_ref()], &spl_fishing::ID);
+  let payment_token_mint = Pubkey::new_unique();
+  let user_payment_token_amount = if is_token_fee { PAYMENT_TOKEN_UNIT_VALUE + TOKEN_FEE_AMOUNT } else { PAYMENT_TOKEN_UNIT_VALUE };
+  program_test.add_account(game, anchor_account(spl_fishing::ID, &Game {
+    bump: game_bump,
+    authority: game_authority,
+    payment_token_mint,
+    payment_token_unit_value: PAYMENT_TOKEN_UNIT_VALUE,
+    reward_tokens: vec![RewardTokenInfo { id: 1, mint: Pubkey::new_unique(), unit_value: 1, decimals: 0, rarity: 1, num_units: 1, num_winners: 0 }],
+    ..Default::default()
+  }, std::mem::size_of::<Game>() + 8 + MAX_NUM_REWARD_TOKENS * std::mem::size_of::<RewardTokenInfo>() + MAX_NUM_REVEAL_PENDING_PLAYERS * 32));
+
+  program_test.add_account(payment_token_mint, packed_account(spl_token::state::Mint {
+    mint_authority: COption::None,
+    supply: user_payment_token_amount,
+    decimals: 0,
+    is_initialized: true,
+    freeze_authority: COption::None,
+  }));
+
+  let user_payment_token_vault = Pubkey::new_unique();
+  program_test.add_account(user_payment_token_vault, token_account(payment_token_mint, player.pubkey(), user_payment_token_amount));
+
+  let (payment_token_vault, _) = Pubkey::find_program_address(&[VAULT_SEED, game.as_ref(), payment_token_mint.as_ref()], &spl_fishing::ID);
+  program_test.add_account(payment_token_vault, token_account(payment_token_mint, game, 0));
+
+  // flat fee config of flip, split 70/30
+  let fee_wallets = vec![
+    FeeWallet { address: Pubkey::new_unique(), fee_percent: PERCENT_DENOMINATOR * 7 / 10 },
+    FeeWallet { address: Pubkey::new_unique(), fee_percent: PERCENT_DENOMINATOR * 3 / 10 },
+  ];
+  let mut fee_wallet_token_accounts = Vec::new();
+  for fee_wallet in fee_wallets.iter() {
+    program_test.add_account(fee_wallet.address, Account::new(FEE_WALLET_BALANCE, 0, &system_program::ID));
+
+    if is_token_fee {
+      let fee_wallet_token_account = Pubkey::new_unique();
+      program_test.add_account(fee_wallet_token_account, token_account(payment_token_mint, fee_wallet.address, 0));
+      fee_wallet_token_accounts.push(fee_wallet_token_account);
+    }
+  }
+
+  let (fee_config, fee_config_bump) = find_config_address(&spl_fishing::ID, FLIP_INSTRUCTION_INDEX as u64);
+  program_test.add_account(fee_config, anchor_account(fee_governance_hub::ID, &Config {
+    bump: fee_config_bump,
+    program: spl_fishing::ID,
+    fee_instruction_index: FLIP_INSTRUCTION_INDEX as u8,
+    fee_amount: if is_token_fee { TOKEN_FEE_AMOUNT } else { FEE_AMOUNT },
+    fee_wallets: fee_wallets.clone(),
+    fee_instruction_name: "flip".to_string(),
+    fee_mint: if is_token_fee { payment_token_mint } else { Pubkey::default() },
+    version: CONFIG_VERSION,
+    ..Default::default()
+  }, Config::space(fee_wallets.len())));
+
+  Fixture {
+    context: program_test.start_with_context().await,
+    player,
+    game,
+    payment_token_mint,
+    user_payment_token_vault,
+    payment_token_vault,
+    fee_config,
+    fee_wallets,
+    fee_wallet_token_accounts,
+  }
+}
+
+impl Fixture {
+  // returns the transaction fee the player paid
+  async fn flip(&mut self, remaining_accounts: Vec<AccountMeta>) -> std::result::Result<u64, BanksClientError> {
+    let mut accounts = spl_fishing::accounts::FlipCtx {
+      authority: self.player.pubkey(),
+      game: self.game,
+      payment_token_mint: self.payment_token_mint,
+      user_payment_token_vault: self.user_payment_token_vault,
+      payment_token_vault: self.payment_token_vault,
+      fee_accounts: spl_fishing::accounts::FeeAccounts {
+        fee_config: self.fee_config,
+        fee_instruction_config: None,
+        fee_global_config: None,
+        fee_vault: None,
+        fee_vault_token_account: None,
+        fee_stats: None,
+        fee_caller: find_fee_caller_address(&spl_fishing::ID).0,
+        this_program: spl_fishing::ID,
+        fee_governance_hub: fee_governance_hub::ID,
+      },
+      token_program: spl_token::ID,
+      system_program: system_program::ID,
+    }.to_account_metas(None);
+    accounts.extend(remaining_accounts);
+
+    let transaction = Transaction::new_signed_with_payer(
+      &[Instruction { program_id: spl_fishing::ID, accounts, data: spl_fishing::instruction::Flip {}.data() }],
+      Some(&self.player.pubkey()),
+      &[&self.player],
+      self.context.last_blockhash,
+    );
+
+    let transaction_fee = self.context.banks_client.get_fee_for_message(transaction.message.clone()).await?.unwrap();
+    self.context.banks_client.process_transaction(transaction).await?;
+
+    Ok(transaction_fee)
+  }
+
+  async fn lamports(&mut self, address: Pubkey) -> u64 {
+    self.context.banks_client.get_balance(address).await.unwrap()
+  }
+
+  async fn token_amount(&mut self, address: Pubkey) -> u64 {
+    let account = self.context.banks_client.get_account(address).await.unwrap().unwrap();
+    spl_token::state::Account::unpack(&account.data).unwrap().amount
+  }
+}
+
+#[tokio::test]
+async fn flip_charges_fees_of_non_admin_player() {
+  let mut fixture = setup(false).await;
+  let fee_wallet_accounts = fixture.fee_wallets.iter().map(|fee_wallet| AccountMeta::new(fee_wallet.address, false)).collect();
+
+  let transaction_fee = fixture.flip(fee_wallet_accounts).await.unwrap();
+
+  assert_eq!(fixture.lamports(fixture.player.pubkey()).await, FEE_WALLET_BALANCE - FEE_AMOUNT - transaction_fee);
+
+  for fee_wallet in fixture.fee_wallets.clone() {
+    let fee_amount = FEE_AMOUNT * fee_wallet.fee_percent / PERCENT_DENOMINATOR;
+    assert_eq!(fixture.lamports(fee_wallet.address).await, FEE_WALLET_BALANCE + fee_amount);
+  }
+
+  assert_eq!(fixture.token_amount(fixture.user_payment_token_vault).await, 0);
+  assert_eq!(fixture.token_amount(fixture.payment_token_vault).await, PAYMENT_TOKEN_UNIT_VALUE);
+
+  let game_account = fixture.context.banks_client.get_account(fixture.game).await.unwrap().unwrap();
+  let game = Game::try_deserialize(&mut &game_account.data[..]).unwrap();
+  assert_eq!(game.num_flips, 1);
+  assert_eq!(game.reveal_pending_players, vec![fixture.player.pubkey()]);
+}
+
+#[tokio::test]
+async fn flip_charges_token_fees_of_non_admin_player() {
+  let mut fixture = setup(true).await;
+  let fee_wallet_token_accounts = fixture.fee_wallet_token_accounts.iter().map(|address| AccountMeta::new(*address, false)).collect();
+
+  let transaction_fee = fixture.flip(fee_wallet_token_accounts).await.unwrap();
+
+  assert_eq!(fixture.lamports(fixture.player.pubkey()).await, FEE_WALLET_BALANCE - transaction_fee);
+  for (fee_wallet, fee_wallet_token_account) in fixture.fee_wallets.clone().into_iter().zip(fixture.fee_wallet_token_accounts.clone()) {
+    let fee_amount = TOKEN_FEE_AMOUNT * fee_wallet.fee_percent / PERCENT_DENOMINATOR;
+    assert_eq!(fixture.token_amount(fee_wallet_token_account).await, fee_amount);
+  }
+
+  assert_eq!(fixture.token_amount(fixture.user_payment_token_vault).await, 0);
+  assert_eq!(fixture.token_amount(fixture.payment_token_vault).await, PAYMENT_TOKEN_UNIT_VALUE);
+}
+
+#[tokio::test]
+async fn flip_fails_without_fee_wallets() {
+  let mut fixture = setup(false).await;
+
+  assert!(fixture.flip(Vec::new()).await.is_err());
+
+  let game_account = fixture.context.banks_client.get_account(fixture.game).await.unwrap().unwrap();
+  let game = Game::try_deserialize(&mut &game_account.data[..]).unwrap();
+  assert_eq!(game.num_flips, 0);
+}
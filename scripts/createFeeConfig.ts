@@ -28,13 +28,18 @@ const feeWallets = [
 ];
 const feeAmount = 10_000_000; // 0.01 SOL
 const feeInstructionName = "create_game";
+// fee settings beyond the flat SOL fee, see FeeConfigOptions for the defaults
+const feeConfigOptions = {
+  remainderPolicy: "firstWallet" as const,
+  platformSharePercent: 0,
+};
 
 (async () => {
   try {
     const tx = new anchor.web3.Transaction();
     const connection = getConnection();
 
-    const adminKeypair = getAdminKeypair();
+    const adminKeypair = getAdminKeypair(); // must be an admin of the AdminRegistry
 
     const program = getFeeGovernanceHubProgram(connection);
 
@@ -46,7 +51,8 @@ const feeInstructionName = "create_game";
       feeWallets,
       feeAmount,
       feeInstructionName,
-      program
+      program,
+      feeConfigOptions
     );
 
     tx.add(createConfigIx);